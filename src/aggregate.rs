@@ -0,0 +1,292 @@
+use std::cmp::Ordering;
+use std::iter::{Product, Sum};
+
+use crate::Folder;
+
+/// A folder counting the items.
+pub struct CountFolder {
+    /// The number of items folded so far.
+    count: usize,
+}
+
+impl CountFolder {
+    /// Creates a folder counting the items.
+    pub fn new() -> Self {
+        CountFolder { count: 0 }
+    }
+}
+
+impl Default for CountFolder {
+    fn default() -> Self {
+        CountFolder::new()
+    }
+}
+
+impl<Item> Folder<Item> for CountFolder {
+    type B = usize;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        self.count += i.count();
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.count
+    }
+}
+
+/// A folder summing the items into an `S`.
+///
+/// Every block is summed on its own, then added to the running sum, so `S` must be able to
+/// sum both items and partial sums, like for rayon's `sum`.
+pub struct SumFolder<S> {
+    /// The sum of the items folded so far.
+    sum: S,
+}
+
+impl<S> SumFolder<S> {
+    /// Creates a folder summing items of type `Item`, starting from the sum of no items.
+    pub fn new<Item>() -> Self
+    where S: Sum<Item> {
+        SumFolder { sum: std::iter::empty::<Item>().sum() }
+    }
+}
+
+impl<Item, S> Folder<Item> for SumFolder<S> where
+S: Sum<Item> + Sum<S> {
+    type B = S;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let block: S = i.sum();
+        self.sum = [self.sum, block].into_iter().sum();
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.sum
+    }
+}
+
+/// A folder multiplying the items into a `P`.
+///
+/// Every block is multiplied on its own, then into the running product, so `P` must be able
+/// to multiply both items and partial products, like for rayon's `product`.
+pub struct ProductFolder<P> {
+    /// The product of the items folded so far.
+    product: P,
+}
+
+impl<P> ProductFolder<P> {
+    /// Creates a folder multiplying items of type `Item`, starting from the product of no items.
+    pub fn new<Item>() -> Self
+    where P: Product<Item> {
+        ProductFolder { product: std::iter::empty::<Item>().product() }
+    }
+}
+
+impl<Item, P> Folder<Item> for ProductFolder<P> where
+P: Product<Item> + Product<P> {
+    type B = P;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let block: P = i.product();
+        self.product = [self.product, block].into_iter().product();
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.product
+    }
+}
+
+/// Keeps `best` unless `replace(best, candidate)` says `candidate` should replace it.
+fn merge<T>(best: Option<T>, candidate: Option<T>, replace: impl FnOnce(&T, &T) -> bool) -> Option<T> {
+    match (best, candidate) {
+        (Some(best), Some(candidate)) => Some(if replace(&best, &candidate) { candidate } else { best }),
+        (best, candidate) => best.or(candidate),
+    }
+}
+
+/// A folder searching for the minimum item with respect to a comparison function.
+///
+/// Returns the first of several equally minimum items, like [`Iterator::min_by`].
+pub struct MinByFolder<T, C> {
+    /// The minimum item folded so far.
+    best: Option<T>,
+    /// The comparison function.
+    compare: C,
+}
+
+impl<T, C> MinByFolder<T, C> {
+    /// Creates a folder searching for the minimum item with respect to `compare`.
+    pub fn new(compare: C) -> Self {
+        MinByFolder { best: None, compare }
+    }
+}
+
+impl<Item, C> Folder<Item> for MinByFolder<Item, C> where
+C: FnMut(&Item, &Item) -> Ordering {
+    type B = Option<Item>;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let candidate = i.min_by(&mut self.compare);
+        let compare = &mut self.compare;
+        self.best = merge(self.best, candidate, |best, candidate| compare(candidate, best) == Ordering::Less);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.best
+    }
+}
+
+/// A folder searching for the maximum item with respect to a comparison function.
+///
+/// Returns the last of several equally maximum items, like [`Iterator::max_by`].
+pub struct MaxByFolder<T, C> {
+    /// The maximum item folded so far.
+    best: Option<T>,
+    /// The comparison function.
+    compare: C,
+}
+
+impl<T, C> MaxByFolder<T, C> {
+    /// Creates a folder searching for the maximum item with respect to `compare`.
+    pub fn new(compare: C) -> Self {
+        MaxByFolder { best: None, compare }
+    }
+}
+
+impl<Item, C> Folder<Item> for MaxByFolder<Item, C> where
+C: FnMut(&Item, &Item) -> Ordering {
+    type B = Option<Item>;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let candidate = i.max_by(&mut self.compare);
+        let compare = &mut self.compare;
+        self.best = merge(self.best, candidate, |best, candidate| compare(candidate, best) != Ordering::Less);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.best
+    }
+}
+
+/// A folder searching for the item with the minimum key.
+///
+/// The key is computed once per item. Returns the first of several items with equally
+/// minimum keys, like [`Iterator::min_by_key`].
+pub struct MinByKeyFolder<T, K, F> {
+    /// The key and the item with the minimum key folded so far.
+    best: Option<(K, T)>,
+    /// The key function.
+    key: F,
+}
+
+impl<T, K, F> MinByKeyFolder<T, K, F> {
+    /// Creates a folder searching for the item with the minimum `key`.
+    pub fn new(key: F) -> Self {
+        MinByKeyFolder { best: None, key }
+    }
+}
+
+impl<Item, K, F> Folder<Item> for MinByKeyFolder<Item, K, F> where
+K: Ord,
+F: FnMut(&Item) -> K {
+    type B = Option<Item>;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let key = &mut self.key;
+        let candidate = i.map(|x| (key(&x), x)).min_by(|a, b| a.0.cmp(&b.0));
+        self.best = merge(self.best, candidate, |best, candidate| candidate.0 < best.0);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.best.map(|(_, x)| x)
+    }
+}
+
+/// A folder searching for the item with the maximum key.
+///
+/// The key is computed once per item. Returns the last of several items with equally
+/// maximum keys, like [`Iterator::max_by_key`].
+pub struct MaxByKeyFolder<T, K, F> {
+    /// The key and the item with the maximum key folded so far.
+    best: Option<(K, T)>,
+    /// The key function.
+    key: F,
+}
+
+impl<T, K, F> MaxByKeyFolder<T, K, F> {
+    /// Creates a folder searching for the item with the maximum `key`.
+    pub fn new(key: F) -> Self {
+        MaxByKeyFolder { best: None, key }
+    }
+}
+
+impl<Item, K, F> Folder<Item> for MaxByKeyFolder<Item, K, F> where
+K: Ord,
+F: FnMut(&Item) -> K {
+    type B = Option<Item>;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let key = &mut self.key;
+        let candidate = i.map(|x| (key(&x), x)).max_by(|a, b| a.0.cmp(&b.0));
+        self.best = merge(self.best, candidate, |best, candidate| candidate.0 >= best.0);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.best.map(|(_, x)| x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_iter, AsyncIterator, Iter};
+
+    #[tokio::test]
+    async fn test_count_through_filter() {
+        let data: Vec<i32> = (0..10_000).map(|x| x - 5_000).collect();
+        let positive = |x: &&i32| **x > 0;
+        assert_eq!(Iter::new(&data).with_block_size(333).filter(positive).count().await, 4_999);
+        assert_eq!(Iter::new(&data[..0]).count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_sum_and_product() {
+        let data: Vec<u64> = (1..=10_000).collect();
+        assert_eq!(Iter::new(&data).with_block_size(100).sum::<u64>().await, 50_005_000);
+
+        let halve = |x: &u64| *x as f64 / 2.0;
+        let product = Iter::new(&data[..10]).with_block_size(3).map(halve).product::<f64>().await;
+        assert_eq!(product, 3_628_800.0 / 1_024.0);
+        assert_eq!(Iter::new(&data[..0]).product::<u64>().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_min_max() {
+        let data: Vec<i32> = (0..10_000).map(|x| (x * 7_919) % 10_007).collect();
+        assert_eq!(Iter::new(&data).with_block_size(64).min().await, data.iter().min());
+        assert_eq!(Iter::new(&data).with_block_size(64).max().await, data.iter().max());
+        assert_eq!(Iter::new(&data[..0]).max().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_min_max_by_keep_std_tie_breaking() {
+        let data: Vec<(u8, usize)> = (0..1_000).map(|i| ((i % 10) as u8, i)).collect();
+        let by_first = |a: &&(u8, usize), b: &&(u8, usize)| a.0.cmp(&b.0);
+        assert_eq!(Iter::new(&data).with_block_size(7).min_by(by_first).await, data.iter().min_by(by_first));
+        assert_eq!(Iter::new(&data).with_block_size(7).max_by(by_first).await, data.iter().max_by(by_first));
+        assert_eq!(
+            Iter::new(&data).with_block_size(7).min_by_key(|x| x.0).await,
+            data.iter().min_by_key(|x| x.0),
+        );
+        assert_eq!(
+            from_iter(data.clone()).with_block_size(7).max_by_key(|x| x.0).await,
+            data.into_iter().max_by_key(|x| x.0),
+        );
+    }
+}
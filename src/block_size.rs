@@ -0,0 +1,231 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::DEFAULT_BLOCK_SIZE;
+
+/// The policy deciding how many elements are folded between two yield points.
+#[derive(Clone, Debug)]
+pub enum BlockSize {
+    /// Every block holds the same number of elements.
+    Fixed(usize),
+    /// Block sizes are tuned while folding so each block takes roughly a target time.
+    Adaptive(Adaptive),
+}
+
+impl Default for BlockSize {
+    fn default() -> Self {
+        BlockSize::Fixed(DEFAULT_BLOCK_SIZE)
+    }
+}
+
+impl From<usize> for BlockSize {
+    fn from(block_size: usize) -> Self {
+        BlockSize::Fixed(block_size)
+    }
+}
+
+impl From<Adaptive> for BlockSize {
+    fn from(adaptive: Adaptive) -> Self {
+        BlockSize::Adaptive(adaptive)
+    }
+}
+
+/// An adaptive block size policy.
+///
+/// The driver measures how long each block took and scales the next block so it lasts about
+/// `target`. A block may at most double in size or shrink to a quarter of its size from one
+/// block to the next, so a single noisy measurement can't throw the policy off.
+#[derive(Clone, Debug)]
+pub struct Adaptive {
+    /// The time each block should take.
+    target: Duration,
+    /// The size of the first block.
+    initial: usize,
+    /// The smallest block size the policy may pick.
+    min: usize,
+    /// The largest block size the policy may pick.
+    max: usize,
+    /// The record of every block folded with this policy.
+    history: BlockHistory,
+}
+
+impl Adaptive {
+    /// Creates a policy aiming for blocks lasting `target`, starting at [`DEFAULT_BLOCK_SIZE`].
+    pub fn new(target: Duration) -> Self {
+        Adaptive {
+            target,
+            initial: DEFAULT_BLOCK_SIZE,
+            min: 1,
+            max: usize::MAX,
+            history: BlockHistory::default(),
+        }
+    }
+
+    /// Sets the size of the first block, which is clamped to the [bounds](Adaptive::with_bounds).
+    ///
+    /// # Panics
+    /// Panics if `initial` is zero.
+    pub fn with_initial(mut self, initial: usize) -> Self {
+        assert!(initial > 0, "block size must be at least 1");
+        self.initial = initial;
+        self
+    }
+
+    /// Bounds the block sizes the policy may pick to `min..=max`.
+    ///
+    /// # Panics
+    /// Panics if `min` is zero or greater than `max`.
+    pub fn with_bounds(mut self, min: usize, max: usize) -> Self {
+        assert!(min > 0, "block size must be at least 1");
+        assert!(min <= max, "minimum block size is greater than the maximum");
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Returns a handle on the record of the blocks folded with this policy.
+    ///
+    /// The handle is shared with every clone of the policy, so it can be inspected after the
+    /// fold consumed the iterator.
+    pub fn history(&self) -> BlockHistory {
+        self.history.clone()
+    }
+}
+
+/// A single block folded by the driver.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockRecord {
+    /// The number of elements in the block.
+    pub size: usize,
+    /// The time it took to fold the block.
+    pub elapsed: Duration,
+}
+
+/// A shared record of the blocks folded with an [`Adaptive`] policy.
+#[derive(Clone, Debug, Default)]
+pub struct BlockHistory {
+    records: Arc<Mutex<Vec<BlockRecord>>>,
+}
+
+impl BlockHistory {
+    /// Returns every block recorded so far, in folding order.
+    pub fn records(&self) -> Vec<BlockRecord> {
+        self.records.lock().unwrap().clone()
+    }
+
+    /// Returns the size of every block recorded so far, in folding order.
+    pub fn sizes(&self) -> Vec<usize> {
+        self.records.lock().unwrap().iter().map(|r| r.size).collect()
+    }
+
+    /// Forgets every recorded block.
+    pub fn clear(&self) {
+        self.records.lock().unwrap().clear();
+    }
+
+    fn push(&self, record: BlockRecord) {
+        self.records.lock().unwrap().push(record);
+    }
+}
+
+/// The per-fold state of a [`BlockSize`] policy, used by the drivers.
+pub(crate) struct Chunker {
+    /// The size of the next block.
+    size: usize,
+    /// The adaptive policy, if any.
+    adaptive: Option<Adaptive>,
+}
+
+impl Chunker {
+    /// Creates the state of `block_size`, starting an adaptive policy at its initial size
+    /// clamped to its bounds.
+    pub(crate) fn new(block_size: BlockSize) -> Self {
+        match block_size {
+            BlockSize::Fixed(size) => Chunker { size, adaptive: None },
+            BlockSize::Adaptive(adaptive) => {
+                let size = adaptive.initial.clamp(adaptive.min, adaptive.max);
+                Chunker { size, adaptive: Some(adaptive) }
+            }
+        }
+    }
+
+    /// Returns the size of the next block.
+    pub(crate) fn next_size(&self) -> usize {
+        self.size
+    }
+
+    /// Starts timing a block, if the policy needs it.
+    pub(crate) fn start(&self) -> Option<Instant> {
+        self.adaptive.as_ref().map(|_| Instant::now())
+    }
+
+    /// Records a block of `len` elements started at `started` and picks the next block size.
+    pub(crate) fn finish(&mut self, started: Option<Instant>, len: usize) {
+        let (Some(adaptive), Some(started)) = (&self.adaptive, started) else {
+            return;
+        };
+        let elapsed = started.elapsed();
+        adaptive.history.push(BlockRecord { size: len, elapsed });
+
+        // A short last block says little about the cost of a full one.
+        if len < self.size {
+            return;
+        }
+        let ideal = len as u128 * adaptive.target.as_nanos() / elapsed.as_nanos().max(1);
+        let ideal = usize::try_from(ideal).unwrap_or(usize::MAX);
+        self.size = ideal
+            .clamp((len / 4).max(1), len.saturating_mul(2))
+            .clamp(adaptive.min, adaptive.max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunker_grows_fast_blocks() {
+        let adaptive = Adaptive::new(Duration::from_secs(1)).with_initial(100);
+        let history = adaptive.history();
+        let mut chunker = Chunker::new(adaptive.into());
+        for _ in 0..3 {
+            let size = chunker.next_size();
+            chunker.finish(chunker.start(), size);
+        }
+        assert_eq!(history.sizes(), vec![100, 200, 400]);
+        assert_eq!(chunker.next_size(), 800);
+    }
+
+    #[test]
+    fn test_chunker_shrinks_slow_blocks() {
+        let adaptive = Adaptive::new(Duration::from_nanos(1)).with_initial(1_000).with_bounds(10, 1_000);
+        let mut chunker = Chunker::new(adaptive.into());
+        let mut sizes = Vec::new();
+        for _ in 0..4 {
+            let size = chunker.next_size();
+            sizes.push(size);
+            let started = chunker.start();
+            std::thread::sleep(Duration::from_micros(50));
+            chunker.finish(started, size);
+        }
+        assert_eq!(sizes, vec![1_000, 250, 62, 15]);
+        assert_eq!(chunker.next_size(), 10);
+    }
+
+    #[test]
+    fn test_chunker_clamps_initial_size() {
+        let adaptive = Adaptive::new(Duration::from_micros(200)).with_bounds(10, 100);
+        assert_eq!(Chunker::new(adaptive.into()).next_size(), 100);
+
+        let adaptive = Adaptive::new(Duration::from_micros(200)).with_initial(5).with_bounds(10, 100);
+        assert_eq!(Chunker::new(adaptive.into()).next_size(), 10);
+    }
+
+    #[test]
+    fn test_chunker_fixed_records_nothing() {
+        let mut chunker = Chunker::new(BlockSize::Fixed(7));
+        assert!(chunker.start().is_none());
+        chunker.finish(None, 7);
+        assert_eq!(chunker.next_size(), 7);
+    }
+}
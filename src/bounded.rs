@@ -0,0 +1,401 @@
+use std::future::Future;
+
+use crate::{AsyncIterator, Folder};
+
+/// An iterator yielding the items of the base iterator while a predicate holds, like
+/// [`std::iter::TakeWhile`].
+pub struct TakeWhile<I, P> {
+    /// The base iterator.
+    base: I,
+    /// The predicate the yielded items satisfy.
+    pred: P,
+}
+
+impl<I, P> TakeWhile<I, P> {
+    /// Creates an iterator yielding the items of `base` while `pred` holds.
+    pub(crate) fn new(base: I, pred: P) -> Self {
+        TakeWhile { base, pred }
+    }
+}
+
+/// An asynchronous iterator that yields the items of another one while a predicate holds.
+///
+/// # Type Parameters
+/// - `I`: The inner asynchronous iterator type that this `TakeWhile` iterator adapts.
+/// - `P`: The type of the predicate.
+impl<I, P> AsyncIterator for TakeWhile<I, P>
+where
+    I: AsyncIterator + Send,
+    P: FnMut(&I::Item) -> bool + Send,
+{
+    /// The type of items yielded by the iterator.
+    type Item = I::Item;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for folding the items.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        self.base.fold_folder(TakeWhileFolder { folder, pred: self.pred, done: false })
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<I, P> crate::local::LocalAsyncIterator for TakeWhile<I, P>
+where
+    I: crate::local::LocalAsyncIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        self.base.fold_folder(TakeWhileFolder { folder, pred: self.pred, done: false })
+    }
+}
+
+/// A folder for the take-while iterator.
+///
+/// Gets [full](Folder::full) at the first item failing the predicate, so the driver stops
+/// the fold.
+pub struct TakeWhileFolder<F, P> {
+    /// The inner folder.
+    folder: F,
+    /// The predicate the folded items satisfy.
+    pred: P,
+    /// Whether an item failed the predicate.
+    done: bool,
+}
+
+impl<Item, F, P> Folder<Item> for TakeWhileFolder<F, P> where
+F: Folder<Item>,
+P: FnMut(&Item) -> bool {
+    type B = F::B;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        if self.done {
+            return self;
+        }
+        let (pred, done) = (&mut self.pred, &mut self.done);
+        self.folder = self.folder.fold(i.take_while(|item| {
+            *done = !pred(item);
+            !*done
+        }));
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.into_result()
+    }
+
+    fn full(&self) -> bool {
+        self.done || self.folder.full()
+    }
+
+    /// Forwards to the inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    /// Forwards to the inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+/// An iterator skipping the items of the base iterator while a predicate holds, like
+/// [`std::iter::SkipWhile`].
+pub struct SkipWhile<I, P> {
+    /// The base iterator.
+    base: I,
+    /// The predicate the skipped items satisfy.
+    pred: P,
+}
+
+impl<I, P> SkipWhile<I, P> {
+    /// Creates an iterator skipping the items of `base` while `pred` holds.
+    pub(crate) fn new(base: I, pred: P) -> Self {
+        SkipWhile { base, pred }
+    }
+}
+
+/// An asynchronous iterator that skips the items of another one while a predicate holds.
+///
+/// # Type Parameters
+/// - `I`: The inner asynchronous iterator type that this `SkipWhile` iterator adapts.
+/// - `P`: The type of the predicate.
+impl<I, P> AsyncIterator for SkipWhile<I, P>
+where
+    I: AsyncIterator + Send,
+    P: FnMut(&I::Item) -> bool + Send,
+{
+    /// The type of items yielded by the iterator.
+    type Item = I::Item;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for folding the items.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        self.base.fold_folder(SkipWhileFolder { folder, pred: self.pred, skipping: true })
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<I, P> crate::local::LocalAsyncIterator for SkipWhile<I, P>
+where
+    I: crate::local::LocalAsyncIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        self.base.fold_folder(SkipWhileFolder { folder, pred: self.pred, skipping: true })
+    }
+}
+
+/// A folder for the skip-while iterator.
+///
+/// Keeps whether it is still skipping, so the predicate isn't called again once an item
+/// failed it, even in a later block.
+pub struct SkipWhileFolder<F, P> {
+    /// The inner folder.
+    folder: F,
+    /// The predicate the skipped items satisfy.
+    pred: P,
+    /// Whether every item so far satisfied the predicate.
+    skipping: bool,
+}
+
+impl<Item, F, P> Folder<Item> for SkipWhileFolder<F, P> where
+F: Folder<Item>,
+P: FnMut(&Item) -> bool {
+    type B = F::B;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let (pred, skipping) = (&mut self.pred, &mut self.skipping);
+        self.folder = self.folder.fold(i.skip_while(|item| {
+            *skipping = *skipping && pred(item);
+            *skipping
+        }));
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.into_result()
+    }
+
+    /// Forwards to the inner folder.
+    fn full(&self) -> bool {
+        self.folder.full()
+    }
+
+    /// Forwards to the inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    /// Forwards to the inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+/// An iterator mapping the items of the base iterator while the operation returns `Some`,
+/// like [`std::iter::MapWhile`].
+pub struct MapWhile<I, O> {
+    /// The base iterator.
+    base: I,
+    /// The mapping operation.
+    op: O,
+}
+
+impl<I, O> MapWhile<I, O> {
+    /// Creates an iterator mapping the items of `base` with `op` while it returns `Some`.
+    pub(crate) fn new(base: I, op: O) -> Self {
+        MapWhile { base, op }
+    }
+}
+
+/// An asynchronous iterator that maps the items of another one while the operation returns
+/// `Some`.
+///
+/// # Type Parameters
+/// - `R`: The type of items produced by the mapping operation.
+/// - `I`: The inner asynchronous iterator type that this `MapWhile` iterator adapts.
+/// - `O`: The type of the mapping operation.
+impl<R, I, O> AsyncIterator for MapWhile<I, O>
+where
+    I: AsyncIterator + Send,
+    O: FnMut(I::Item) -> Option<R> + Send,
+{
+    /// The type of items yielded by the iterator.
+    type Item = R;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for folding the items.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        self.base.fold_folder(MapWhileFolder { folder, op: self.op, done: false })
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<R, I, O> crate::local::LocalAsyncIterator for MapWhile<I, O>
+where
+    I: crate::local::LocalAsyncIterator,
+    O: FnMut(I::Item) -> Option<R>,
+{
+    type Item = R;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        self.base.fold_folder(MapWhileFolder { folder, op: self.op, done: false })
+    }
+}
+
+/// A folder for the map-while iterator.
+///
+/// Gets [full](Folder::full) at the first item mapped to `None`, so the driver stops the
+/// fold.
+pub struct MapWhileFolder<F, O> {
+    /// The inner folder.
+    folder: F,
+    /// The mapping operation.
+    op: O,
+    /// Whether an item was mapped to `None`.
+    done: bool,
+}
+
+impl<Item, R, F, O> Folder<Item> for MapWhileFolder<F, O> where
+F: Folder<R>,
+O: FnMut(Item) -> Option<R> {
+    type B = F::B;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        if self.done {
+            return self;
+        }
+        let (op, done) = (&mut self.op, &mut self.done);
+        self.folder = self.folder.fold(i.map_while(|item| {
+            let mapped = op(item);
+            *done = mapped.is_none();
+            mapped
+        }));
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.into_result()
+    }
+
+    fn full(&self) -> bool {
+        self.done || self.folder.full()
+    }
+
+    /// Forwards to the inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    /// Forwards to the inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_iter, AsyncIterator, Iter, NeverYield};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_take_while_stops_the_fold() {
+        // A sorted buffer, scanned up to a threshold.
+        let data: Vec<u32> = (0..100_000).map(|x| x * 3).collect();
+        let seen = AtomicUsize::new(0);
+        let count = |&x: &u32| {
+            seen.fetch_add(1, Ordering::Relaxed);
+            x
+        };
+        let below = Iter::new(&data)
+            .with_block_size(64)
+            .with_yield_strategy(NeverYield)
+            .map(count)
+            .take_while(|&x| x < 1_000)
+            .collect_vec()
+            .await;
+        assert_eq!(below, (0..334).map(|x| x * 3).collect::<Vec<_>>());
+        // Only the first item past the threshold was mapped.
+        assert_eq!(seen.load(Ordering::Relaxed), 335);
+    }
+
+    #[tokio::test]
+    async fn test_skip_while_across_blocks() {
+        let data = [1, 3, 5, 7, 8, 9, 10, 11, 2];
+        let calls = AtomicUsize::new(0);
+        let odd = |&&x: &&u32| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            x % 2 == 1
+        };
+        let rest = Iter::new(&data).with_block_size(2).skip_while(odd).collect_vec().await;
+        assert_eq!(rest, [&8, &9, &10, &11, &2]);
+        // The predicate isn't called past the first item failing it.
+        assert_eq!(calls.load(Ordering::Relaxed), 5);
+    }
+
+    #[tokio::test]
+    async fn test_map_while() {
+        let records = ["4", "8", "15", "x", "16", "23"];
+        let parsed =
+            from_iter(records).with_block_size(2).map_while(|r| r.parse::<u32>().ok()).collect_vec().await;
+        assert_eq!(parsed, [4, 8, 15]);
+
+        let all = from_iter(0..1_000u32).with_block_size(7).map_while(|x| Some(x * 2)).count().await;
+        assert_eq!(all, 1_000);
+    }
+}
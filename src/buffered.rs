@@ -0,0 +1,303 @@
+use std::collections::VecDeque;
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+
+use crate::{AsyncIterator, Folder};
+
+/// The panic message for a folder used while it is settling, which the drivers never do.
+const SETTLING: &str = "folder used while settling";
+
+/// An iterator awaiting up to `limit` of its futures at once and yielding their outputs.
+///
+/// Created by [`buffered`](AsyncIterator::buffered), which keeps the input order, and
+/// [`buffer_unordered`](AsyncIterator::buffer_unordered), which yields the outputs as they
+/// complete.
+pub struct Buffered<I> {
+    /// The base iterator, yielding futures.
+    base: I,
+    /// The maximum number of futures awaited at once.
+    limit: usize,
+    /// Whether the outputs keep the order of the futures.
+    ordered: bool,
+}
+
+impl<I> Buffered<I> {
+    /// Creates an iterator awaiting up to `limit` futures of `base` at once.
+    ///
+    /// # Panics
+    /// Panics if `limit` is zero.
+    pub(crate) fn new(base: I, limit: usize, ordered: bool) -> Self {
+        assert!(limit > 0, "buffer limit must be at least 1");
+        Buffered { base, limit, ordered }
+    }
+}
+
+/// An asynchronous iterator that awaits several futures of another iterator at once.
+///
+/// # Type Parameters
+/// - `I`: The inner asynchronous iterator type that this `Buffered` iterator adapts, yielding
+///   futures.
+impl<I> AsyncIterator for Buffered<I>
+where
+    I: AsyncIterator + Send,
+    I::Item: Future + Send,
+    <I::Item as Future>::Output: Send,
+{
+    /// The type of items yielded by the iterator.
+    type Item = <I::Item as Future>::Output;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for folding the items.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        let buffered_folder = BufferedFolder::new(folder, self.limit, self.ordered);
+        self.base.fold_folder(buffered_folder)
+    }
+}
+
+/// A future being awaited by a [`BufferedFolder`], or its output.
+enum Slot<Fut: Future> {
+    /// The future, still pending.
+    Running(Pin<Box<Fut>>),
+    /// The output of the future, waiting for the futures before it in ordered mode.
+    Done(Fut::Output),
+}
+
+/// A folder for the buffered iterators.
+///
+/// Buffers the futures of a block, then starts them when the block is
+/// [settled](Folder::settle), keeping up to `limit` of them running and passing the outputs to
+/// the inner folder as they become available. Settling returns once every future of the block
+/// started, so the last ones keep running while the next block is folded, and the futures
+/// still running when the fold stops are awaited when the folder is [flushed](Folder::flush).
+///
+/// The running futures are all polled whenever one of them wakes the task, so `limit` is
+/// meant for a few dozen concurrent requests, not thousands.
+pub struct BufferedFolder<F, Fut: Future> {
+    /// The inner folder, only missing while settling.
+    folder: Option<F>,
+    /// The futures buffered but not started yet.
+    futures: VecDeque<Fut>,
+    /// The futures started, in order, and the outputs not folded yet.
+    slots: VecDeque<Slot<Fut>>,
+    /// The maximum number of futures awaited at once.
+    limit: usize,
+    /// Whether the outputs keep the order of the futures.
+    ordered: bool,
+}
+
+impl<F, Fut: Future> BufferedFolder<F, Fut> {
+    /// Creates a folder awaiting up to `limit` futures at once, passing their outputs to
+    /// `folder` in order if `ordered` is set, or as they complete otherwise.
+    ///
+    /// # Panics
+    /// Panics if `limit` is zero.
+    pub fn new(folder: F, limit: usize, ordered: bool) -> Self {
+        assert!(limit > 0, "buffer limit must be at least 1");
+        let slots = VecDeque::with_capacity(limit);
+        BufferedFolder { folder: Some(folder), futures: VecDeque::new(), slots, limit, ordered }
+    }
+
+    /// Awaits the running futures, starting the buffered ones as slots free up, until every
+    /// buffered future started, or until every future completed if `drain` is set. Stops
+    /// starting new ones once the inner folder is full.
+    async fn run(&mut self, drain: bool)
+    where
+        F: Folder<Fut::Output>,
+    {
+        let mut folder = self.folder.take().expect(SETTLING);
+        loop {
+            while self.slots.len() < self.limit && !folder.full() {
+                match self.futures.pop_front() {
+                    Some(future) => self.slots.push_back(Slot::Running(Box::pin(future))),
+                    None => break,
+                }
+            }
+            let waiting = !self.futures.is_empty() && !folder.full();
+            if self.slots.is_empty() || !(drain || waiting) {
+                break;
+            }
+            let (slots, ordered) = (&mut self.slots, self.ordered);
+            let outputs = poll_fn(|cx| {
+                for slot in slots.iter_mut() {
+                    if let Slot::Running(future) = slot {
+                        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                            *slot = Slot::Done(output);
+                        }
+                    }
+                }
+                let outputs = take_done(slots, ordered);
+                if outputs.is_empty() { Poll::Pending } else { Poll::Ready(outputs) }
+            })
+            .await;
+            folder = folder.fold(outputs.into_iter());
+            folder.settle().await;
+        }
+        self.folder = Some(folder);
+    }
+}
+
+impl<Fut, F> Folder<Fut> for BufferedFolder<F, Fut> where
+F: Folder<Fut::Output> + Send,
+Fut: Future + Send,
+Fut::Output: Send {
+    /// The type of result produced by the adapted folder.
+    type B = F::B;
+
+    /// Buffers the futures until the block is settled.
+    fn fold<I: Iterator<Item=Fut>>(mut self, i: I) -> Self {
+        self.futures.extend(i);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.expect(SETTLING).into_result()
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn full(&self) -> bool {
+        self.folder.as_ref().is_some_and(|folder| folder.full())
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.as_ref().is_some_and(|folder| folder.cancelled())
+    }
+
+    /// Starts the buffered futures, keeping up to `limit` of them running, until they all
+    /// started or the inner folder is full.
+    async fn settle(&mut self) {
+        self.run(false).await;
+    }
+
+    /// Awaits the futures still running, then forwards to the adapted inner folder.
+    async fn flush(&mut self) {
+        self.run(true).await;
+        self.folder.as_mut().expect(SETTLING).flush().await;
+    }
+}
+
+/// Removes the outputs ready to be folded from `slots`: the leading ones if `ordered` is set,
+/// every one otherwise.
+fn take_done<Fut: Future>(slots: &mut VecDeque<Slot<Fut>>, ordered: bool) -> Vec<Fut::Output> {
+    let mut outputs = Vec::new();
+    if ordered {
+        while let Some(Slot::Done(_)) = slots.front() {
+            if let Some(Slot::Done(output)) = slots.pop_front() {
+                outputs.push(output);
+            }
+        }
+    } else {
+        for slot in std::mem::take(slots) {
+            match slot {
+                Slot::Done(output) => outputs.push(output),
+                running => slots.push_back(running),
+            }
+        }
+    }
+    outputs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_iter, AsyncIterator, Iter};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Counts the futures running at once and the most that ever did.
+    #[derive(Clone, Default)]
+    struct InFlight {
+        now: Arc<AtomicUsize>,
+        max: Arc<AtomicUsize>,
+    }
+
+    impl InFlight {
+        /// Returns a future sleeping for `ms` milliseconds before returning `x`.
+        fn sleep(&self, x: u64, ms: u64) -> impl std::future::Future<Output = u64> + Send + 'static {
+            let this = self.clone();
+            async move {
+                let now = this.now.fetch_add(1, Ordering::SeqCst) + 1;
+                this.max.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(ms)).await;
+                this.now.fetch_sub(1, Ordering::SeqCst);
+                x
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_buffered_keeps_order_within_the_limit() {
+        let in_flight = InFlight::default();
+        let lookup = {
+            let in_flight = in_flight.clone();
+            move |x: u64| in_flight.sleep(x, (x * 7) % 5)
+        };
+        let result = from_iter(0..40u64).with_block_size(16).map(lookup).buffered(4).collect_vec().await;
+        assert_eq!(result, (0..40).collect::<Vec<_>>());
+        assert_eq!(in_flight.max.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_buffered_keeps_futures_running_across_blocks() {
+        let in_flight = InFlight::default();
+        let lookup = {
+            let in_flight = in_flight.clone();
+            move |x: u64| in_flight.sleep(x, 5)
+        };
+        let result = from_iter(0..64u64).with_block_size(4).map(lookup).buffered(16).collect_vec().await;
+        assert_eq!(result, (0..64).collect::<Vec<_>>());
+        assert_eq!(in_flight.max.load(Ordering::SeqCst), 16);
+
+        let lookup = {
+            let in_flight = in_flight.clone();
+            move |x: u64| in_flight.sleep(x, 5)
+        };
+        let sum = from_iter(0..64u64).with_block_size(4).map(lookup).buffer_unordered(16).sum::<u64>().await;
+        assert_eq!(sum, 2_016);
+        // The futures still running after the last block were awaited.
+        assert_eq!(in_flight.now.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_buffer_unordered_yields_outputs_as_they_complete() {
+        let in_flight = InFlight::default();
+        let lookup = {
+            let in_flight = in_flight.clone();
+            move |&x: &u64| in_flight.sleep(x, if x == 0 { 50 } else { 1 })
+        };
+        let data: Vec<u64> = (0..8).collect();
+        let result = Iter::new(&data).map(lookup).buffer_unordered(8).collect_vec().await;
+        assert_eq!(result.len(), 8);
+        assert_eq!(result.last(), Some(&0));
+        assert_eq!(in_flight.max.load(Ordering::SeqCst), 8);
+    }
+
+    #[tokio::test]
+    async fn test_buffered_stops_starting_futures_once_full() {
+        let started = Arc::new(AtomicUsize::new(0));
+        let lookup = {
+            let started = started.clone();
+            move |x: u32| {
+                let started = started.clone();
+                async move {
+                    started.fetch_add(1, Ordering::SeqCst);
+                    x
+                }
+            }
+        };
+        let found = from_iter(0..1_000u32).map(lookup).buffered(3).find(|&x| x == 10).await;
+        assert_eq!(found, Some(10));
+        // At most one window of futures was started past the match.
+        assert!(started.load(Ordering::SeqCst) <= 11 + 3);
+    }
+}
@@ -0,0 +1,254 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::Folder;
+
+/// A signal telling a fold to stop at the next block boundary.
+pub trait CancelSignal {
+    /// Returns whether the fold was asked to stop.
+    fn is_cancelled(&self) -> bool;
+}
+
+/// A cancellation token shared by a fold and the tasks that may cancel it.
+///
+/// Clones share the same state, so cancelling any of them cancels them all.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    /// Whether the token was cancelled.
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Creates a token that isn't cancelled yet.
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    /// Cancels the token, and every clone of it.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+impl CancelSignal for CancelToken {
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// A signal that is never raised.
+pub(crate) struct NeverCancel;
+
+impl CancelSignal for NeverCancel {
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+impl<C: CancelSignal + ?Sized> CancelSignal for &C {
+    fn is_cancelled(&self) -> bool {
+        (**self).is_cancelled()
+    }
+}
+
+#[cfg(feature = "tokio-util")]
+impl CancelSignal for tokio_util::sync::CancellationToken {
+    fn is_cancelled(&self) -> bool {
+        tokio_util::sync::CancellationToken::is_cancelled(self)
+    }
+}
+
+/// The outcome of a fold that can be cancelled.
+#[derive(Debug)]
+pub enum Cancellable<B, F> {
+    /// The fold went through the whole input, with this result.
+    Done(B),
+    /// The fold was cancelled, with the folder holding the items folded so far.
+    Cancelled(F),
+}
+
+impl<B, F> Cancellable<B, F> {
+    /// Returns whether the fold was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Cancellable::Cancelled(_))
+    }
+}
+
+/// A folder stopping the fold once a [`CancelSignal`] is raised.
+///
+/// Reports itself [cancelled](Folder::cancelled) once the signal is raised, so the driver stops
+/// at the next block boundary and the inner folder is handed back instead of its result. The
+/// signal is never read in the middle of a block, so adapters settling a block item by item
+/// don't drop the rest of it.
+pub struct CancelFolder<F, C> {
+    /// The inner folder.
+    folder: F,
+    /// The signal to watch.
+    signal: C,
+    /// Whether the fold was stopped by the signal.
+    stopped: Cell<bool>,
+}
+
+impl<F, C> CancelFolder<F, C> {
+    /// Creates a folder passing the items to `folder` until `signal` is raised.
+    pub fn new(folder: F, signal: C) -> Self {
+        CancelFolder { folder, signal, stopped: Cell::new(false) }
+    }
+}
+
+impl<Item, F, C> Folder<Item> for CancelFolder<F, C> where
+F: Folder<Item> + Send,
+C: CancelSignal + Send {
+    type B = Cancellable<F::B, F>;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        self.folder = self.folder.fold(i);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        if self.stopped.get() {
+            Cancellable::Cancelled(self.folder)
+        } else {
+            Cancellable::Done(self.folder.into_result())
+        }
+    }
+
+    /// Forwards to the inner folder.
+    fn full(&self) -> bool {
+        self.folder.full()
+    }
+
+    /// Checks the signal, or forwards to the inner folder.
+    fn cancelled(&self) -> bool {
+        self.stopped.set(self.signal.is_cancelled());
+        self.stopped.get() || self.folder.cancelled()
+    }
+
+    /// Forwards to the inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AsyncIterator, BasicFolder, Iter};
+
+    #[tokio::test]
+    async fn test_cancel_at_block_boundary() {
+        let data: Vec<u64> = (0..1_000_000).collect();
+        let token = CancelToken::new();
+        let cancel_at_1_500 = {
+            let token = token.clone();
+            move |acc: u64, &x: &u64| {
+                if x == 1_500 {
+                    token.cancel();
+                }
+                acc + x
+            }
+        };
+        let folder = BasicFolder { init: 0, op: cancel_at_1_500 };
+        let outcome =
+            Iter::new(&data).with_block_size(1_000).fold_folder_with_cancel(folder, &token).await;
+        let Cancellable::Cancelled(partial) = outcome else {
+            panic!("the fold wasn't cancelled");
+        };
+
+        // The block holding the 1_500 was folded, and nothing after it.
+        let resumed = Iter::new(&data[2_000..]).fold_folder(partial).await;
+        assert_eq!(resumed, data.iter().sum::<u64>());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_through_adapters() {
+        let data: Vec<u32> = (0..10_000).collect();
+        let token = CancelToken::new();
+        token.cancel();
+        let double = |x: &u32| x * 2;
+        let outcome = Iter::new(&data)
+            .with_block_size(1_000)
+            .map(double)
+            .filter(|x| x % 3 == 0)
+            .fold_folder_with_cancel(BasicFolder { init: 0, op: |acc: u32, _| acc + 1 }, token.clone())
+            .await;
+        match outcome {
+            // A token cancelled beforehand still lets the first block through.
+            Cancellable::Cancelled(partial) => assert_eq!(partial.into_result(), 334),
+            Cancellable::Done(_) => panic!("the fold wasn't cancelled"),
+        }
+
+        let sum = BasicFolder { init: 0, op: |acc: u32, x| acc + x };
+        let outcome = Iter::new(&data).map(double).fold_folder_with_cancel(sum, CancelToken::new()).await;
+        assert!(matches!(outcome, Cancellable::Done(99_990_000)));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_keeps_whole_async_blocks() {
+        let data: Vec<u32> = (0..1_000).collect();
+        let count = |acc: u32, _| acc + 1;
+        let cancel_at_10 = |token: CancelToken| move |x: &u32| {
+            if *x == 10 {
+                token.cancel();
+            }
+            std::future::ready(*x)
+        };
+
+        let token = CancelToken::new();
+        let outcome = Iter::new(&data)
+            .with_block_size(50)
+            .then(cancel_at_10(token.clone()))
+            .fold_folder_with_cancel(BasicFolder { init: 0, op: count }, &token)
+            .await;
+        let Cancellable::Cancelled(partial) = outcome else {
+            panic!("the fold wasn't cancelled");
+        };
+        assert_eq!(partial.into_result(), 50);
+
+        let token = CancelToken::new();
+        let outcome = Iter::new(&data)
+            .with_block_size(50)
+            .map(cancel_at_10(token.clone()))
+            .buffered(2)
+            .fold_folder_with_cancel(BasicFolder { init: 0, op: |acc: u32, x| acc + x }, &token)
+            .await;
+        let Cancellable::Cancelled(partial) = outcome else {
+            panic!("the fold wasn't cancelled");
+        };
+        // Every item of the block holding the 10 was folded, so the fold resumes after it.
+        let resumed = Iter::new(&data[50..]).map(|&x| x).fold_folder(partial).await;
+        assert_eq!(resumed, data.iter().sum::<u32>());
+    }
+
+    #[cfg(feature = "tokio-util")]
+    #[tokio::test]
+    async fn test_cancel_with_tokio_util_token() {
+        let data: Vec<u32> = (0..100_000).collect();
+        let token = tokio_util::sync::CancellationToken::new();
+        let canceller = {
+            let token = token.clone();
+            move |acc: u32, _: &u32| {
+                if acc == 10 {
+                    token.cancel();
+                }
+                acc + 1
+            }
+        };
+        let outcome = Iter::new(&data)
+            .with_block_size(64)
+            .fold_folder_with_cancel(BasicFolder { init: 0, op: canceller }, token)
+            .await;
+        match outcome {
+            Cancellable::Cancelled(partial) => assert_eq!(partial.into_result(), 64),
+            Cancellable::Done(_) => panic!("the fold wasn't cancelled"),
+        }
+    }
+}
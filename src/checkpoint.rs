@@ -0,0 +1,288 @@
+use std::future::Future;
+
+use crate::driver::{drive_until, Schedule};
+use crate::{
+    AsyncIterator, CancelSignal, CancelToken, Cancellable, Filter, FilterFolder, Folder, Iter,
+    Map, MapFolder, YieldStrategy,
+};
+
+/// A fold stopped at a block boundary: the folder holding the items folded so far, and the
+/// position reached in the slice.
+#[derive(Debug)]
+pub struct Checkpoint<F> {
+    /// The folder holding the items folded so far.
+    folder: F,
+    /// The index of the first item of the slice that wasn't folded.
+    position: usize,
+}
+
+impl<F> Checkpoint<F> {
+    /// Creates a checkpoint at the start of the slice, so resuming it folds the whole input
+    /// into `folder`.
+    pub fn new(folder: F) -> Self {
+        Checkpoint { folder, position: 0 }
+    }
+
+    /// Returns the index of the first item of the slice that wasn't folded.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the folder holding the items folded so far.
+    pub fn folder(&self) -> &F {
+        &self.folder
+    }
+
+    /// Converts the checkpoint into the folder holding the items folded so far.
+    pub fn into_folder(self) -> F {
+        self.folder
+    }
+
+    /// Replaces the folder with `op(folder)`, keeping the position.
+    fn map<G>(self, op: impl FnOnce(F) -> G) -> Checkpoint<G> {
+        Checkpoint { folder: op(self.folder), position: self.position }
+    }
+}
+
+/// An asynchronous iterator over a slice, whose folds can stop at a [`Checkpoint`] and resume
+/// from it later without folding any item twice.
+///
+/// Implemented for [`Iter`], and for [`map`](AsyncIterator::map) and
+/// [`filter`](AsyncIterator::filter) over it. Resuming needs the same pipeline over the same
+/// slice, so the position means the same item.
+pub trait ResumableAsyncIterator: AsyncIterator {
+    /// Folds the items from the position of `checkpoint` into its folder, until `signal` is
+    /// raised.
+    ///
+    /// The signal is checked at every block boundary, like the one of
+    /// [`fold_folder_with_cancel`](AsyncIterator::fold_folder_with_cancel), so every call folds
+    /// at least one block. Once it is raised, the fold stops and returns
+    /// [`Cancellable::Cancelled`] with a new checkpoint.
+    ///
+    /// # Panics
+    /// Panics if the position of `checkpoint` is past the end of the slice.
+    fn resume_with_cancel<F, C>(
+        self,
+        checkpoint: Checkpoint<F>,
+        signal: C,
+    ) -> impl Future<Output = Cancellable<F::B, Checkpoint<F>>> + Send
+    where
+        Self: Send,
+        F: Folder<Self::Item> + Send,
+        C: CancelSignal + Send;
+
+    /// Folds the iterator using the provided folder until `signal` is raised, returning a
+    /// [`Checkpoint`] if it was.
+    fn fold_folder_with_checkpoint<F, C>(
+        self,
+        folder: F,
+        signal: C,
+    ) -> impl Future<Output = Cancellable<F::B, Checkpoint<F>>> + Send
+    where
+        Self: Send,
+        F: Folder<Self::Item> + Send,
+        C: CancelSignal + Send,
+    {
+        self.resume_with_cancel(Checkpoint::new(folder), signal)
+    }
+
+    /// Folds the items from the position of `checkpoint` into its folder, up to the end.
+    ///
+    /// # Panics
+    /// Panics if the position of `checkpoint` is past the end of the slice.
+    fn resume<F>(self, checkpoint: Checkpoint<F>) -> impl Future<Output = F::B> + Send
+    where
+        Self: Send,
+        F: Folder<Self::Item> + Send,
+    {
+        let outcome = self.resume_with_cancel(checkpoint, CancelToken::new());
+        async move {
+            match outcome.await {
+                Cancellable::Done(result) => result,
+                Cancellable::Cancelled(_) => unreachable!("the token is never cancelled"),
+            }
+        }
+    }
+}
+
+impl<'a, T: 'a + Sync, Y: YieldStrategy> ResumableAsyncIterator for Iter<'a, T, Y> {
+    fn resume_with_cancel<F, C>(
+        self,
+        checkpoint: Checkpoint<F>,
+        signal: C,
+    ) -> impl Future<Output = Cancellable<F::B, Checkpoint<F>>> + Send
+    where
+        Self: Send,
+        F: Folder<Self::Item> + Send,
+        C: CancelSignal + Send,
+    {
+        let (slice, schedule) = self.into_parts();
+        resume_slice(slice, schedule, checkpoint, signal)
+    }
+}
+
+/// Folds the items of `slice` from the position of `checkpoint` into its folder, pacing the
+/// fold with `schedule`, until `signal` is raised.
+async fn resume_slice<'a, T, F, C, Y>(
+    slice: &'a [T],
+    schedule: Schedule<Y>,
+    checkpoint: Checkpoint<F>,
+    signal: C,
+) -> Cancellable<F::B, Checkpoint<F>>
+where
+    T: Sync,
+    F: Folder<&'a T> + Send,
+    C: CancelSignal + Send,
+    Y: YieldStrategy,
+{
+    match drive_until(&slice[checkpoint.position..], schedule, checkpoint.folder, signal).await {
+        (Cancellable::Done(result), _) => Cancellable::Done(result),
+        (Cancellable::Cancelled(folder), rest) => {
+            Cancellable::Cancelled(Checkpoint { folder, position: slice.len() - rest.len() })
+        }
+    }
+}
+
+impl<R, I, O> ResumableAsyncIterator for Map<I, O>
+where
+    I: ResumableAsyncIterator + Send,
+    O: FnMut(I::Item) -> R + Send,
+{
+    fn resume_with_cancel<F, C>(
+        self,
+        checkpoint: Checkpoint<F>,
+        signal: C,
+    ) -> impl Future<Output = Cancellable<F::B, Checkpoint<F>>> + Send
+    where
+        Self: Send,
+        F: Folder<Self::Item> + Send,
+        C: CancelSignal + Send,
+    {
+        let checkpoint = checkpoint.map(|folder| MapFolder { folder, op: self.op });
+        let outcome = self.base.resume_with_cancel(checkpoint, signal);
+        async move {
+            match outcome.await {
+                Cancellable::Done(result) => Cancellable::Done(result),
+                Cancellable::Cancelled(checkpoint) => {
+                    Cancellable::Cancelled(checkpoint.map(|map_folder| map_folder.folder))
+                }
+            }
+        }
+    }
+}
+
+impl<I, P> ResumableAsyncIterator for Filter<I, P>
+where
+    I: ResumableAsyncIterator + Send,
+    P: FnMut(&I::Item) -> bool + Send,
+{
+    fn resume_with_cancel<F, C>(
+        self,
+        checkpoint: Checkpoint<F>,
+        signal: C,
+    ) -> impl Future<Output = Cancellable<F::B, Checkpoint<F>>> + Send
+    where
+        Self: Send,
+        F: Folder<Self::Item> + Send,
+        C: CancelSignal + Send,
+    {
+        let checkpoint = checkpoint.map(|folder| FilterFolder { folder, pred: self.pred });
+        let outcome = self.base.resume_with_cancel(checkpoint, signal);
+        async move {
+            match outcome.await {
+                Cancellable::Done(result) => Cancellable::Done(result),
+                Cancellable::Cancelled(checkpoint) => {
+                    Cancellable::Cancelled(checkpoint.map(|filter_folder| filter_folder.folder))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BasicFolder, ExtendFolder, UntilDeadline};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_resume_from_checkpoint() {
+        let data: Vec<u64> = (0..100_000).collect();
+        let seen = AtomicUsize::new(0);
+        let token = CancelToken::new();
+        let sum = |acc: u64, &x: &u64| {
+            if seen.fetch_add(1, Ordering::Relaxed) == 25_000 {
+                token.cancel();
+            }
+            acc + x
+        };
+        let outcome = Iter::new(&data)
+            .with_block_size(1_000)
+            .fold_folder_with_checkpoint(BasicFolder { init: 0, op: sum }, &token)
+            .await;
+        let Cancellable::Cancelled(checkpoint) = outcome else {
+            panic!("the fold wasn't cancelled");
+        };
+        assert_eq!(checkpoint.position(), 26_000);
+
+        let total = Iter::new(&data).with_block_size(1_000).resume(checkpoint).await;
+        assert_eq!(total, data.iter().sum::<u64>());
+        // No item was folded twice.
+        assert_eq!(seen.load(Ordering::Relaxed), 100_000);
+    }
+
+    #[tokio::test]
+    async fn test_resume_through_map_and_filter() {
+        let data: Vec<u32> = (0..10_000).collect();
+        let square = |x: &u32| x * x;
+        let even = |x: &u32| x.is_multiple_of(2);
+        let folder = ExtendFolder::new(Vec::new());
+
+        // Every fold is preempted after a single block, then resumed with a new pipeline.
+        let mut checkpoint = Checkpoint::new(folder);
+        let mut preemptions = 0;
+        let squares = loop {
+            let token = CancelToken::new();
+            token.cancel();
+            let pipeline = Iter::new(&data).with_block_size(512).map(square).filter(even);
+            match pipeline.resume_with_cancel(checkpoint, token).await {
+                Cancellable::Done(squares) => break squares,
+                Cancellable::Cancelled(next) => {
+                    assert_eq!(next.position(), (preemptions + 1) * 512);
+                    checkpoint = next;
+                    preemptions += 1;
+                }
+            }
+        };
+        assert_eq!(preemptions, 19);
+        let expected: Vec<u32> = data.iter().map(square).filter(even).collect();
+        assert_eq!(squares, expected);
+    }
+
+    #[tokio::test]
+    async fn test_raised_signal_folds_one_block_everywhere() {
+        let data: Vec<u64> = (0..10_000).collect();
+        let token = CancelToken::new();
+        token.cancel();
+        let sum = || BasicFolder { init: 0, op: |acc: u64, &x: &u64| acc + x };
+        let first_block: u64 = data[..1_000].iter().sum();
+
+        let outcome = Iter::new(&data).with_block_size(1_000).fold_folder_with_cancel(sum(), &token).await;
+        let Cancellable::Cancelled(partial) = outcome else {
+            panic!("the fold wasn't cancelled");
+        };
+        assert_eq!(partial.into_result(), first_block);
+
+        let outcome =
+            Iter::new(&data).with_block_size(1_000).fold_folder_with_checkpoint(sum(), &token).await;
+        let Cancellable::Cancelled(checkpoint) = outcome else {
+            panic!("the fold wasn't cancelled");
+        };
+        assert_eq!(checkpoint.position(), 1_000);
+        assert_eq!(checkpoint.into_folder().into_result(), first_block);
+
+        let deadline = std::time::Instant::now();
+        let outcome = Iter::new(&data).with_block_size(1_000).fold_folder_until(sum(), deadline).await;
+        assert_eq!(outcome, UntilDeadline::Partial { result: first_block, folded: 1_000 });
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::future::Future;
+use std::hash::{BuildHasher, Hash};
+
+use crate::Folder;
+
+/// Conversion from an [`AsyncIterator`](crate::AsyncIterator), used by
+/// [`AsyncIterator::collect`](crate::AsyncIterator::collect).
+///
+/// This is the asynchronous counterpart of [`FromIterator`]: the collection provides a folder
+/// building it block by block. Any collection implementing [`Extend`] can implement it with an
+/// [`ExtendFolder`], returning `ExtendFolder::new` of an empty collection from `folder`; without an
+/// implementation, [`AsyncIterator::collect_into`](crate::AsyncIterator::collect_into) extends it.
+pub trait FromAsyncIterator<Item>: Sized {
+    /// The folder building the collection.
+    type Folder: Folder<Item, B = Self>;
+
+    /// Creates a folder building an empty collection.
+    fn folder() -> Self::Folder;
+}
+
+/// A folder extending a collection with every block.
+pub struct ExtendFolder<C> {
+    /// The collection being extended.
+    collection: C,
+}
+
+impl<C> ExtendFolder<C> {
+    /// Creates a folder extending `collection`.
+    pub fn new(collection: C) -> Self {
+        ExtendFolder { collection }
+    }
+}
+
+impl<Item, C> Folder<Item> for ExtendFolder<C> where
+C: Extend<Item> {
+    type B = C;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        self.collection.extend(i);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.collection
+    }
+}
+
+impl<T> FromAsyncIterator<T> for Vec<T> {
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(Vec::new())
+    }
+}
+
+impl<T> FromAsyncIterator<T> for VecDeque<T> {
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(VecDeque::new())
+    }
+}
+
+impl<T> FromAsyncIterator<T> for LinkedList<T> {
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(LinkedList::new())
+    }
+}
+
+impl<T: Ord> FromAsyncIterator<T> for BinaryHeap<T> {
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(BinaryHeap::new())
+    }
+}
+
+impl<T: Ord> FromAsyncIterator<T> for BTreeSet<T> {
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(BTreeSet::new())
+    }
+}
+
+impl<K: Ord, V> FromAsyncIterator<(K, V)> for BTreeMap<K, V> {
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(BTreeMap::new())
+    }
+}
+
+impl<T, S> FromAsyncIterator<T> for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(HashSet::default())
+    }
+}
+
+impl<K, V, S> FromAsyncIterator<(K, V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(HashMap::default())
+    }
+}
+
+impl FromAsyncIterator<char> for String {
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(String::new())
+    }
+}
+
+impl FromAsyncIterator<&char> for String {
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(String::new())
+    }
+}
+
+impl FromAsyncIterator<&str> for String {
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(String::new())
+    }
+}
+
+impl FromAsyncIterator<String> for String {
+    type Folder = ExtendFolder<Self>;
+
+    fn folder() -> Self::Folder {
+        ExtendFolder::new(String::new())
+    }
+}
+
+/// A folder collecting `Ok` values until the first `Err`.
+///
+/// Stops folding the block at the first `Err`, which becomes the result, and gets
+/// [full](Folder::full) so the driver doesn't fold the next blocks.
+pub struct ResultFolder<F, E> {
+    /// The folder collecting the `Ok` values.
+    folder: F,
+    /// The first error.
+    error: Option<E>,
+}
+
+impl<F, E> ResultFolder<F, E> {
+    /// Creates a folder passing `Ok` values to `folder` until the first `Err`.
+    pub fn new(folder: F) -> Self {
+        ResultFolder { folder, error: None }
+    }
+}
+
+impl<T, E, F> Folder<Result<T, E>> for ResultFolder<F, E> where
+F: Folder<T> {
+    type B = Result<F::B, E>;
+
+    fn fold<I: Iterator<Item=Result<T, E>>>(mut self, i: I) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+        let error = &mut self.error;
+        let oks = i.map_while(|item| item.map_err(|e| *error = Some(e)).ok());
+        self.folder = self.folder.fold(oks);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.folder.into_result()),
+        }
+    }
+
+    fn full(&self) -> bool {
+        self.error.is_some() || self.folder.full()
+    }
+
+    /// Forwards to the inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+impl<T, E, C> FromAsyncIterator<Result<T, E>> for Result<C, E> where
+C: FromAsyncIterator<T> {
+    type Folder = ResultFolder<C::Folder, E>;
+
+    fn folder() -> Self::Folder {
+        ResultFolder::new(C::folder())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_iter, AsyncIterator, Iter};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_collect_collections() {
+        let data: Vec<u32> = (0..1_000).collect();
+
+        let square = |x: u32| (x, x * x);
+        let squares: HashMap<u32, u32> = from_iter(0..1_000u32).map(square).collect().await;
+        assert_eq!(squares.len(), 1_000);
+        assert_eq!(squares[&31], 961);
+
+        let residue = |x: u32| x % 7;
+        let residues: BTreeSet<u32> = from_iter(data.clone()).map(residue).collect().await;
+        assert_eq!(residues, (0..7).collect());
+
+        let deque: VecDeque<&u32> = Iter::new(&data).with_block_size(10).collect().await;
+        assert_eq!(deque.len(), 1_000);
+        assert_eq!(deque.back(), Some(&&999));
+    }
+
+    #[derive(Default)]
+    struct Histogram([usize; 4]);
+
+    impl Extend<u32> for Histogram {
+        fn extend<I: IntoIterator<Item = u32>>(&mut self, items: I) {
+            items.into_iter().for_each(|x| self.0[x as usize % 4] += 1);
+        }
+    }
+
+    impl FromAsyncIterator<u32> for Histogram {
+        type Folder = ExtendFolder<Self>;
+
+        fn folder() -> Self::Folder {
+            ExtendFolder::new(Histogram::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_collect_custom_collection() {
+        let histogram: Histogram = from_iter(0..1_000u32).with_block_size(64).collect().await;
+        assert_eq!(histogram.0, [250; 4]);
+
+        let histogram = from_iter(0..10u32).collect_into(Histogram::default()).await;
+        assert_eq!(histogram.0, [3, 3, 2, 2]);
+
+        let data: Vec<u32> = (0..100).collect();
+        let copied = |x: &u32| *x;
+        let extended = Iter::new(&data).map(copied).collect_into(vec![7, 8]).await;
+        assert_eq!(extended.len(), 102);
+        assert_eq!(extended[..3], [7, 8, 0]);
+    }
+
+    #[tokio::test]
+    async fn test_collect_string() {
+        let chars: Vec<char> = "cooperative".chars().collect();
+        let text: String = Iter::new(&chars).with_block_size(3).collect().await;
+        assert_eq!(text, "cooperative");
+
+        let words = ["fold", "by", "block"];
+        let deref = |w: &&'static str| *w;
+        let text: String = Iter::new(&words).map(deref).collect().await;
+        assert_eq!(text, "foldbyblock");
+    }
+
+    #[tokio::test]
+    async fn test_collect_result() {
+        let ok: Result<Vec<u32>, String> = from_iter(0..100u32).map(Ok).collect().await;
+        assert_eq!(ok, Ok((0..100).collect()));
+
+        let seen = AtomicUsize::new(0);
+        let parse = |x: u32| {
+            seen.fetch_add(1, Ordering::Relaxed);
+            if x == 150 { Err(format!("bad record {x}")) } else { Ok(x) }
+        };
+        let err: Result<Vec<u32>, String> = from_iter(0..1_000_000u32)
+            .with_block_size(100)
+            .map(parse)
+            .collect()
+            .await;
+        assert_eq!(err, Err("bad record 150".to_string()));
+        // Neither the rest of the block holding the error nor the following blocks were parsed.
+        assert_eq!(seen.load(Ordering::Relaxed), 151);
+    }
+}
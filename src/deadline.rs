@@ -0,0 +1,161 @@
+use std::future::Future;
+use std::time::Instant;
+
+use crate::{CancelSignal, Folder};
+
+/// A deadline is raised once it passes.
+impl CancelSignal for Instant {
+    fn is_cancelled(&self) -> bool {
+        Instant::now() >= *self
+    }
+}
+
+/// The outcome of a fold bounded by a deadline.
+#[derive(Debug, PartialEq, Eq)]
+pub enum UntilDeadline<B> {
+    /// The fold went through the whole input before the deadline, with this result.
+    Complete(B),
+    /// The deadline passed first.
+    Partial {
+        /// The result of the items folded before the deadline.
+        result: B,
+        /// The number of items that reached the folder before the deadline.
+        ///
+        /// Items dropped by a `filter` or a similar adapter aren't counted, so this is not the
+        /// number of source elements scanned: use
+        /// [`with_progress`](crate::Iter::with_progress) to get that one.
+        folded: usize,
+    },
+}
+
+impl<B> UntilDeadline<B> {
+    /// Returns whether the fold went through the whole input.
+    pub fn is_complete(&self) -> bool {
+        matches!(self, UntilDeadline::Complete(_))
+    }
+
+    /// Converts the outcome into the result, complete or not.
+    pub fn into_result(self) -> B {
+        match self {
+            UntilDeadline::Complete(result) | UntilDeadline::Partial { result, .. } => result,
+        }
+    }
+}
+
+/// A folder counting the items passed to the inner folder.
+pub(crate) struct CountingFolder<F> {
+    /// The inner folder.
+    folder: F,
+    /// The number of items passed to the inner folder.
+    folded: usize,
+}
+
+impl<F> CountingFolder<F> {
+    /// Creates a folder counting the items passed to `folder`.
+    pub(crate) fn new(folder: F) -> Self {
+        CountingFolder { folder, folded: 0 }
+    }
+
+    /// Converts the folder into the partial outcome of a fold stopped by a deadline.
+    pub(crate) fn into_partial<Item>(self) -> UntilDeadline<F::B>
+    where F: Folder<Item> {
+        UntilDeadline::Partial { result: self.folder.into_result(), folded: self.folded }
+    }
+}
+
+impl<Item, F> Folder<Item> for CountingFolder<F> where
+F: Folder<Item> {
+    type B = F::B;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let folded = &mut self.folded;
+        self.folder = self.folder.fold(i.inspect(|_| *folded += 1));
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.into_result()
+    }
+
+    /// Forwards to the inner folder.
+    fn full(&self) -> bool {
+        self.folder.full()
+    }
+
+    /// Forwards to the inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    /// Forwards to the inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_iter, AsyncIterator, Iter, SumFolder};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_fold_until_complete() {
+        let data: Vec<u64> = (0..100_000).collect();
+        let deadline = Instant::now() + Duration::from_secs(60);
+        let outcome = Iter::new(&data).fold_until(0, |acc, &x| acc + x, deadline).await;
+        assert_eq!(outcome, UntilDeadline::Complete(4_999_950_000));
+        assert!(outcome.is_complete());
+
+        // A passed deadline stops the fold after the first block.
+        let outcome = Iter::new(&data)
+            .with_block_size(1_000)
+            .fold_until(7, |acc, &x| acc + x, Instant::now())
+            .await;
+        assert_eq!(outcome, UntilDeadline::Partial { result: 499_507, folded: 1_000 });
+    }
+
+    #[tokio::test]
+    async fn test_fold_until_partial_at_block_boundary() {
+        let data: Vec<u64> = (0..10_000).collect();
+        let deadline = Instant::now() + Duration::from_millis(50);
+        // Folding the 150 runs past the deadline.
+        let slow_at_150 = |&x: &u64| {
+            if x == 150 {
+                std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+            }
+            x
+        };
+        let outcome = Iter::new(&data)
+            .with_block_size(100)
+            .map(slow_at_150)
+            .filter(|x| x.is_multiple_of(2))
+            .fold_folder_until(SumFolder::new::<u64>(), deadline)
+            .await;
+        // The block holding the 150 was folded, and nothing after it. Of the 200 elements
+        // scanned, only the 100 even ones reached the folder.
+        assert_eq!(outcome, UntilDeadline::Partial { result: 9_900u64, folded: 100 });
+        assert_eq!(outcome.into_result(), 9_900);
+    }
+
+    #[tokio::test]
+    async fn test_fold_until_folds_whole_async_blocks() {
+        let deadline = Instant::now() + Duration::from_millis(10);
+        let slow = |x: u64| async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            x
+        };
+        let outcome = from_iter(0..300u64)
+            .with_block_size(100)
+            .then(slow)
+            .fold_until(0, |acc, x| acc + x, deadline)
+            .await;
+        // The deadline passes while the first block is settled, which still completes.
+        assert_eq!(outcome, UntilDeadline::Partial { result: 4_950, folded: 100 });
+    }
+}
@@ -0,0 +1,161 @@
+use std::future::Future;
+
+use crate::block_size::{BlockSize, Chunker};
+use crate::yield_strategy::{AlwaysYield, YieldStrategy};
+use crate::cancel::NeverCancel;
+use crate::progress::{ProgressHook, ProgressTracker};
+use crate::{CancelSignal, Cancellable, Folder};
+
+/// How a source paces its fold: the block size policy and the yield strategy, and where the
+/// progress is reported.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Schedule<Y = AlwaysYield> {
+    /// The policy deciding how many elements are folded between two yield points.
+    pub(crate) block_size: BlockSize,
+    /// The strategy deciding whether to yield back to the runtime between two blocks.
+    pub(crate) yield_strategy: Y,
+    /// The callback receiving the progress at every block boundary, if any.
+    pub(crate) progress: Option<ProgressHook>,
+}
+
+impl<Y> Schedule<Y> {
+    /// Sets the block size policy.
+    ///
+    /// # Panics
+    /// Panics if a fixed `block_size` is zero.
+    pub(crate) fn with_block_size(mut self, block_size: impl Into<BlockSize>) -> Self {
+        let block_size = block_size.into();
+        if let BlockSize::Fixed(size) = block_size {
+            assert!(size > 0, "block size must be at least 1");
+        }
+        self.block_size = block_size;
+        self
+    }
+
+    /// Sets the yield strategy.
+    pub(crate) fn with_yield_strategy<Y2: YieldStrategy>(self, yield_strategy: Y2) -> Schedule<Y2> {
+        Schedule { block_size: self.block_size, yield_strategy, progress: self.progress }
+    }
+
+    /// Sets the callback receiving the progress at every block boundary.
+    pub(crate) fn with_progress(mut self, progress: ProgressHook) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+}
+
+/// A source the driver can fold block by block.
+pub(crate) trait Blocks {
+    /// The type of items yielded by the source.
+    type Item;
+
+    /// Gets the items of the next block of at most `n` items ready, for sources producing them
+    /// asynchronously.
+    ///
+    /// The driver awaits it before timing the block, so waiting for the items doesn't count
+    /// towards the block's duration. Defaults to doing nothing.
+    fn fetch(&mut self, n: usize) -> impl Future<Output = ()> + Send {
+        let _ = n;
+        std::future::ready(())
+    }
+
+    /// Folds at most `n` of the remaining items into `folder`.
+    ///
+    /// # Returns
+    /// The updated folder and the number of items folded.
+    fn fold_block<F: Folder<Self::Item>>(&mut self, folder: F, n: usize) -> (F, usize);
+
+    /// Returns whether every item was folded.
+    fn is_exhausted(&mut self) -> bool;
+
+    /// Returns the number of items left to fold, if the source knows it.
+    fn remaining(&self) -> Option<usize>;
+}
+
+impl<'a, T> Blocks for &'a [T] {
+    type Item = &'a T;
+
+    fn fold_block<F: Folder<Self::Item>>(&mut self, folder: F, n: usize) -> (F, usize) {
+        let (left, right) = self.split_at(n.min(self.len()));
+        *self = right;
+        (folder.fold(left.iter()), left.len())
+    }
+
+    fn is_exhausted(&mut self) -> bool {
+        self.is_empty()
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// Folds `source` into `folder` block by block, pacing the fold with `schedule`.
+///
+/// Every block is [settled](Folder::settle) before the next one, then the progress is reported
+/// if the schedule asks for it. Stops early once the folder is [full](Folder::full) or
+/// [cancelled](Folder::cancelled), and [flushes](Folder::flush) the folder before returning.
+///
+/// # Returns
+/// The result of folding, represented by the type `F::B`.
+pub(crate) async fn drive<S, F, Y>(source: S, schedule: Schedule<Y>, folder: F) -> F::B
+where
+    S: Blocks,
+    F: Folder<S::Item>,
+    Y: YieldStrategy,
+{
+    match drive_until(source, schedule, folder, NeverCancel).await.0 {
+        Cancellable::Done(result) => result,
+        Cancellable::Cancelled(folder) => folder.into_result(),
+    }
+}
+
+/// Folds `source` into `folder` like [`drive`], stopping at the first block boundary where
+/// `signal` is raised or the folder is [cancelled](Folder::cancelled).
+///
+/// Both are only checked after a block, so every call folds at least one block.
+///
+/// # Returns
+/// The result of folding, or the folder if the fold was cancelled, and the source holding
+/// the items that weren't folded.
+pub(crate) async fn drive_until<S, F, Y, C>(
+    mut source: S,
+    schedule: Schedule<Y>,
+    mut folder: F,
+    signal: C,
+) -> (Cancellable<F::B, F>, S)
+where
+    S: Blocks,
+    F: Folder<S::Item>,
+    Y: YieldStrategy,
+    C: CancelSignal,
+{
+    let (mut chunker, mut yield_strategy) = (Chunker::new(schedule.block_size), schedule.yield_strategy);
+    let mut progress = schedule.progress.map(ProgressTracker::start);
+    yield_strategy.start();
+    if folder.full() {
+        return (Cancellable::Done(folder.into_result()), source);
+    }
+    loop {
+        let size = chunker.next_size();
+        source.fetch(size).await;
+        let started = chunker.start();
+        let (new_folder, len) = source.fold_block(folder, size);
+        folder = new_folder;
+        folder.settle().await;
+        chunker.finish(started, len);
+        if let Some(progress) = &mut progress {
+            progress.finish_block(len, source.remaining());
+        }
+        let done = source.is_exhausted() || folder.full();
+        if done || signal.is_cancelled() || folder.cancelled() {
+            folder.flush().await;
+            if done {
+                return (Cancellable::Done(folder.into_result()), source);
+            }
+            return (Cancellable::Cancelled(folder), source);
+        }
+
+        yield_strategy.after_block().await;
+    }
+}
@@ -0,0 +1,134 @@
+use crate::Folder;
+
+/// A folder folding the items with a fallible operation, like [`Iterator::try_fold`].
+///
+/// Stops at the first error, which becomes the result, and gets [full](Folder::full), so the
+/// driver doesn't fold the next blocks.
+pub struct TryFolder<B, O, E> {
+    /// The accumulator, or the first error.
+    acc: Result<B, E>,
+    /// The fallible folding operation.
+    op: O,
+}
+
+impl<B, O, E> TryFolder<B, O, E> {
+    /// Creates a folder folding the items with `op`, starting from `init`.
+    pub fn new(init: B, op: O) -> Self {
+        TryFolder { acc: Ok(init), op }
+    }
+}
+
+impl<Item, B, O, E> Folder<Item> for TryFolder<B, O, E> where
+O: FnMut(B, Item) -> Result<B, E> {
+    type B = Result<B, E>;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, mut i: I) -> Self {
+        self.acc = match self.acc {
+            Ok(acc) => i.try_fold(acc, &mut self.op),
+            Err(e) => Err(e),
+        };
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.acc
+    }
+
+    fn full(&self) -> bool {
+        self.acc.is_err()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_iter, AsyncIterator, Iter};
+    use std::collections::HashSet;
+    use std::fmt;
+    use std::num::ParseIntError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// An error of the record checks, built from the parsing errors by `?`.
+    #[derive(Debug, PartialEq)]
+    enum RecordError {
+        Parse(ParseIntError),
+        Negative(i64),
+    }
+
+    impl From<ParseIntError> for RecordError {
+        fn from(e: ParseIntError) -> Self {
+            RecordError::Parse(e)
+        }
+    }
+
+    impl fmt::Display for RecordError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                RecordError::Parse(e) => write!(f, "invalid record: {e}"),
+                RecordError::Negative(x) => write!(f, "negative record: {x}"),
+            }
+        }
+    }
+
+    /// Parses a record, rejecting negative ones.
+    fn parse(record: &str) -> Result<i64, RecordError> {
+        let x: i64 = record.trim().parse()?;
+        if x < 0 {
+            return Err(RecordError::Negative(x));
+        }
+        Ok(x)
+    }
+
+    /// Sums the records, returning the first error.
+    async fn total(records: &[&'static str]) -> Result<i64, RecordError> {
+        let add = |acc: i64, r: &&str| Ok::<_, RecordError>(acc + parse(r)?);
+        let total = Iter::new(records).with_block_size(2).try_fold(0, add).await?;
+        Ok(total)
+    }
+
+    #[tokio::test]
+    async fn test_try_fold_stops_at_first_error() {
+        assert_eq!(total(&["1", " 2", "3 "]).await, Ok(6));
+        assert_eq!(total(&["1", "-2", "x", "4"]).await, Err(RecordError::Negative(-2)));
+        assert!(matches!(total(&["1", "x", "-2"]).await, Err(RecordError::Parse(_))));
+
+        let data: Vec<u32> = (0..100_000).collect();
+        let seen = AtomicUsize::new(0);
+        let checked_add = |acc: u32, &x: &u32| {
+            seen.fetch_add(1, Ordering::Relaxed);
+            acc.checked_add(x).ok_or(x)
+        };
+        let sum = Iter::new(&data).with_block_size(1_000).try_fold(0u32, checked_add).await;
+        // 0 + 1 + ... + 92_682 overflows a u32.
+        assert_eq!(sum, Err(92_682));
+        assert_eq!(seen.load(Ordering::Relaxed), 92_683);
+    }
+
+    #[tokio::test]
+    async fn test_try_for_each() {
+        let mut seen = HashSet::new();
+        let unique = |x: u32| if seen.insert(x) { Ok(()) } else { Err(x) };
+        assert_eq!(from_iter([3, 1, 4, 1, 5]).try_for_each(unique).await, Err(1));
+
+        let mut sum = 0;
+        let add = |x: u32| {
+            sum += x;
+            Ok::<_, String>(())
+        };
+        assert_eq!(from_iter(0..100u32).with_block_size(7).try_for_each(add).await, Ok(()));
+        assert_eq!(sum, 4_950);
+    }
+
+    #[tokio::test]
+    async fn test_try_collect() {
+        let records = ["4", "8", "15", "16", "23", "42"];
+        let parsed: Result<Vec<i64>, RecordError> =
+            Iter::new(&records).map(|r| parse(r)).try_collect().await;
+        assert_eq!(parsed, Ok(vec![4, 8, 15, 16, 23, 42]));
+
+        let records = ["4", "-8", "15"];
+        let parsed: Result<HashSet<i64>, RecordError> =
+            Iter::new(&records).map(|r| parse(r)).try_collect().await;
+        assert_eq!(parsed, Err(RecordError::Negative(-8)));
+        assert_eq!(parsed.unwrap_err().to_string(), "negative record: -8");
+    }
+}
@@ -0,0 +1,172 @@
+use std::future::Future;
+use std::iter::Peekable;
+
+use crate::driver::{drive, Blocks, Schedule};
+use crate::progress::ProgressHook;
+use crate::{AlwaysYield, AsyncIterator, BlockSize, Folder, Progress, YieldStrategy};
+
+/// An asynchronous iterator over the items of any [`Iterator`].
+///
+/// The wrapped iterator is folded block by block, yielding back to the runtime between two
+/// blocks like the slice-based sources. Created with [`from_iter`].
+pub struct FromIter<I: Iterator, Y = AlwaysYield> {
+    /// The items left to fold.
+    iter: Peekable<I>,
+    /// The block size policy and yield strategy pacing the fold.
+    schedule: Schedule<Y>,
+}
+
+/// Creates an asynchronous iterator over the items of `iter`.
+///
+/// Uses the [`DEFAULT_BLOCK_SIZE`](crate::DEFAULT_BLOCK_SIZE) and yields after every block.
+pub fn from_iter<I: IntoIterator>(iter: I) -> FromIter<I::IntoIter> {
+    FromIter::new(iter.into_iter())
+}
+
+impl<I: Iterator> FromIter<I> {
+    /// Creates an asynchronous iterator over the items of `iter`.
+    pub fn new(iter: I) -> Self {
+        FromIter { iter: iter.peekable(), schedule: Schedule::default() }
+    }
+}
+
+impl<I: Iterator, Y> FromIter<I, Y> {
+    /// Sets the policy deciding how many elements are folded between two yield points.
+    ///
+    /// # Panics
+    /// Panics if a fixed `block_size` is zero.
+    pub fn with_block_size(mut self, block_size: impl Into<BlockSize>) -> Self {
+        self.schedule = self.schedule.with_block_size(block_size);
+        self
+    }
+
+    /// Returns the policy deciding how many elements are folded between two yield points.
+    pub fn block_size(&self) -> &BlockSize {
+        &self.schedule.block_size
+    }
+
+    /// Sets the strategy deciding whether to yield back to the runtime between two blocks.
+    pub fn with_yield_strategy<Y2: YieldStrategy>(self, yield_strategy: Y2) -> FromIter<I, Y2> {
+        FromIter { iter: self.iter, schedule: self.schedule.with_yield_strategy(yield_strategy) }
+    }
+
+    /// Sets the callback receiving the [`Progress`] of the fold at every block boundary.
+    ///
+    /// The estimated time left is only known if the iterator's size hint is exact.
+    pub fn with_progress(mut self, callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        self.schedule = self.schedule.with_progress(ProgressHook::new(callback));
+        self
+    }
+}
+
+impl<I: Iterator> Blocks for Peekable<I> {
+    type Item = I::Item;
+
+    fn fold_block<F: Folder<Self::Item>>(&mut self, folder: F, n: usize) -> (F, usize) {
+        let mut len = 0;
+        let folder = folder.fold(self.by_ref().take(n).inspect(|_| len += 1));
+        (folder, len)
+    }
+
+    fn is_exhausted(&mut self) -> bool {
+        self.peek().is_none()
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        match self.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower),
+            _ => None,
+        }
+    }
+}
+
+/// An asynchronous iterator that folds the items of a synchronous iterator.
+///
+/// # Type Parameters
+/// - `I`: The wrapped iterator type.
+/// - `Y`: The strategy deciding whether to yield between two blocks.
+impl<I, Y> AsyncIterator for FromIter<I, Y>
+where
+    I: Iterator + Send,
+    I::Item: Send,
+    Y: YieldStrategy,
+{
+    /// The type of items yielded by the iterator.
+    type Item = I::Item;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for asynchronous folding.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        drive(self.iter, self.schedule, folder)
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<I, Y> crate::local::LocalAsyncIterator for FromIter<I, Y>
+where
+    I: Iterator,
+    Y: YieldStrategy,
+{
+    type Item = I::Item;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        drive(self.iter, self.schedule, folder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_from_range() {
+        let result = from_iter(0..10_000u64).with_block_size(100).async_fold(0, |acc, x| acc + x).await;
+        assert_eq!(result, (0..10_000u64).sum());
+    }
+
+    #[tokio::test]
+    async fn test_from_range_map_filter() {
+        let triple = |x: i32| x * 3;
+        let result = from_iter(0..1_000)
+            .map(triple)
+            .filter(|x| x % 2 == 0)
+            .collect_vec()
+            .await;
+        let expected: Vec<i32> = (0..1_000).map(|x| x * 3).filter(|x| x % 2 == 0).collect();
+        assert_eq!(result, expected);
+    }
+
+    #[tokio::test]
+    async fn test_from_hash_map() {
+        let map: HashMap<u32, u32> = (0..500).map(|k| (k, k * k)).collect();
+        let mut pairs = from_iter(map.iter()).with_block_size(64).collect_vec().await;
+        pairs.sort();
+        let mut expected: Vec<_> = map.iter().collect();
+        expected.sort();
+        assert_eq!(pairs, expected);
+    }
+
+    #[tokio::test]
+    async fn test_from_generator() {
+        let mut state = 1u64;
+        let powers = std::iter::from_fn(move || {
+            state *= 2;
+            (state <= 1 << 20).then_some(state)
+        });
+        let result = from_iter(powers).with_block_size(3).collect_vec().await;
+        assert_eq!(result, (1..=20).map(|i| 1u64 << i).collect::<Vec<_>>());
+    }
+}
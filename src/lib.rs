@@ -1,19 +1,133 @@
-use tokio::task::yield_now;
-use async_trait::async_trait;
+use std::cmp::Ordering;
+use std::future::Future;
+use std::iter::{Product, Sum};
+use std::time::Instant;
+
+mod aggregate;
+mod block_size;
+mod bounded;
+mod buffered;
+mod cancel;
+mod checkpoint;
+mod collect;
+mod deadline;
+mod driver;
+mod fallible;
+mod from_iter;
+/// The local variant of [`AsyncIterator`], outside the crate root so that a glob import doesn't
+/// bring both traits into scope.
+pub mod local;
+mod owned;
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+mod parallel;
+mod positional;
+mod progress;
+mod reduce;
+mod runtime;
+mod search;
+#[cfg(feature = "futures")]
+mod stream;
+mod then;
+mod yield_strategy;
+
+pub use aggregate::{
+    CountFolder, MaxByFolder, MaxByKeyFolder, MinByFolder, MinByKeyFolder, ProductFolder, SumFolder,
+};
+pub use block_size::{Adaptive, BlockHistory, BlockRecord, BlockSize};
+pub use bounded::{MapWhile, MapWhileFolder, SkipWhile, SkipWhileFolder, TakeWhile, TakeWhileFolder};
+pub use buffered::{Buffered, BufferedFolder};
+pub use cancel::{CancelFolder, CancelSignal, CancelToken, Cancellable};
+pub use checkpoint::{Checkpoint, ResumableAsyncIterator};
+pub use collect::{ExtendFolder, FromAsyncIterator, ResultFolder};
+use deadline::CountingFolder;
+pub use deadline::UntilDeadline;
+use driver::{drive, Schedule};
+pub use fallible::TryFolder;
+pub use from_iter::{from_iter, FromIter};
+pub use owned::{ArcIter, VecIter};
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+pub use parallel::{ParallelAsyncIterator, ScopedParallelAsyncIterator, SplitFolder};
+pub use positional::{
+    Enumerate, EnumerateFolder, Skip, SkipFolder, StepBy, StepByFolder, StridedIter, Take, TakeFolder,
+};
+pub use progress::Progress;
+use progress::ProgressHook;
+pub use reduce::{
+    ConcatReducer, MaxReducer, MergeReducer, MinReducer, ReduceFolder, ReduceOp, Reducer, SumReducer,
+};
+pub use runtime::{yield_now, YieldNow};
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+pub use runtime::spawn_blocking;
+pub use search::{AllFolder, AnyFolder, FindFolder, FindMapFolder, PositionFolder};
+#[cfg(feature = "futures")]
+pub use stream::{from_stream, FromStream, IntoStream};
+pub use then::{FilterAsync, FilterAsyncFolder, Then, ThenFolder};
+pub use yield_strategy::{
+    yield_with, AlwaysYield, EveryNBlocks, NeverYield, TimeBudget, YieldStrategy, YieldWith,
+};
+#[cfg(feature = "tokio")]
+pub use yield_strategy::CoopBudget;
+
+/// The number of elements folded between two yield points when no block size is given.
+pub const DEFAULT_BLOCK_SIZE: usize = 1_024;
 
 /// A simple iterator over a slice.
-pub struct Iter<'a, T:'a> {
+pub struct Iter<'a, T:'a, Y = AlwaysYield> {
     /// The underlying slice being iterated over.
-    pub slice: &'a [T]
+    pub slice: &'a [T],
+    /// The block size policy and yield strategy pacing the fold.
+    schedule: Schedule<Y>,
+}
+
+impl<'a, T: 'a> Iter<'a, T> {
+    /// Creates an iterator over `slice` using the [`DEFAULT_BLOCK_SIZE`] and yielding after
+    /// every block.
+    pub fn new(slice: &'a [T]) -> Self {
+        Iter { slice, schedule: Schedule::default() }
+    }
+}
+
+impl<'a, T: 'a, Y> Iter<'a, T, Y> {
+    /// Sets the policy deciding how many elements are folded between two yield points.
+    ///
+    /// Takes either a fixed number of elements or an [`Adaptive`] policy.
+    ///
+    /// # Panics
+    /// Panics if a fixed `block_size` is zero.
+    pub fn with_block_size(mut self, block_size: impl Into<BlockSize>) -> Self {
+        self.schedule = self.schedule.with_block_size(block_size);
+        self
+    }
+
+    /// Returns the policy deciding how many elements are folded between two yield points.
+    pub fn block_size(&self) -> &BlockSize {
+        &self.schedule.block_size
+    }
+
+    /// Sets the strategy deciding whether to yield back to the runtime between two blocks.
+    pub fn with_yield_strategy<Y2: YieldStrategy>(self, yield_strategy: Y2) -> Iter<'a, T, Y2> {
+        Iter { slice: self.slice, schedule: self.schedule.with_yield_strategy(yield_strategy) }
+    }
+
+    /// Sets the callback receiving the [`Progress`] of the fold at every block boundary.
+    pub fn with_progress(mut self, callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        self.schedule = self.schedule.with_progress(ProgressHook::new(callback));
+        self
+    }
+
+    /// Splits the iterator into its slice and its schedule.
+    fn into_parts(self) -> (&'a [T], Schedule<Y>) {
+        (self.slice, self.schedule)
+    }
 }
 
-impl<'a, T: 'a> IntoIterator for Iter<'a, T> {
+impl<'a, T: 'a, Y> IntoIterator for Iter<'a, T, Y> {
     type Item = &'a T;
     type IntoIter = std::slice::Iter<'a, T>;
 
     /// Converts the iterator into a standard slice iterator.
     fn into_iter(self) -> Self::IntoIter {
-        self.slice.into_iter()
+        self.slice.iter()
     }
 }
 
@@ -24,8 +138,8 @@ impl<'a, T: 'a> IntoIterator for Iter<'a, T> {
 /// # Type Parameters
 /// - `'a`: The lifetime of the slice.
 /// - `T`: The type of items in the slice.
-#[async_trait]
-impl<'a, T: 'a + Sync> AsyncIterator for Iter<'a, T> {
+/// - `Y`: The strategy deciding whether to yield between two blocks.
+impl<'a, T: 'a + Sync, Y: YieldStrategy> AsyncIterator for Iter<'a, T, Y> {
     /// The type of items yielded by the iterator.
     type Item = &'a T;
 
@@ -36,47 +150,115 @@ impl<'a, T: 'a + Sync> AsyncIterator for Iter<'a, T> {
     ///
     /// # Returns
     /// The result of folding, represented by the type `F::B`.
-    async fn fold_folder<F:Folder<Self::Item>>(self, mut folder:F) -> F::B
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
     where
     Self: Send,
-    F: Send
-    {
-        let mut slice = self.slice;
-        loop {
-            let block_size = slice.len().max(1_024);
-            let (left, right) = slice.split_at(block_size);
-            folder = folder.fold(left.into_iter());// = left.into_iter().fold(res, folder);
-            if right.is_empty() {
-                return folder.into_result();
-            }
+    F: Folder<Self::Item> + Send
+    {
+        let (slice, schedule) = self.into_parts();
+        drive(slice, schedule, folder)
+    }
+}
 
-            slice = right;
-            yield_now().await;
-        }
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<'a, T: 'a, Y: YieldStrategy> crate::local::LocalAsyncIterator for Iter<'a, T, Y>
+where
+{
+    type Item = &'a T;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        let (slice, schedule) = self.into_parts();
+        drive(slice, schedule, folder)
     }
 }
 
 /// An asynchronous iterator trait.
-#[async_trait]
+///
+/// Every fold returns a `Send` future. [`LocalAsyncIterator`](local::LocalAsyncIterator) is
+/// the variant for iterators and folders that aren't `Send`.
 pub trait AsyncIterator: Sized {
     /// The type of items yielded by the iterator.
     type Item;
 
     /// Asynchronously folds the iterator using the provided folder.
-    async fn fold_folder<F:Folder<Self::Item>>(self, folder:F) -> F::B
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
     where
     Self: Send,
-    F: Send;
+    F: Folder<Self::Item> + Send;
+
+    /// Folds the iterator using the provided folder until `signal` is raised.
+    ///
+    /// The signal is checked at every block boundary, after the block is settled, so at least
+    /// one block is folded even if the signal was raised beforehand. Once it is raised, the fold
+    /// stops and returns [`Cancellable::Cancelled`] with the folder holding the items folded so
+    /// far, every one of them up to the end of a block, so the work can be inspected or resumed.
+    fn fold_folder_with_cancel<F, C>(
+        self,
+        folder: F,
+        signal: C,
+    ) -> impl Future<Output = Cancellable<F::B, F>> + Send
+    where
+        Self: Send,
+        F: Folder<Self::Item> + Send,
+        C: CancelSignal + Send,
+    {
+        self.fold_folder(CancelFolder::new(folder, signal))
+    }
+
+    /// Folds the iterator using the provided folder until `deadline` passes.
+    ///
+    /// The deadline is checked at every block boundary, like the signal of
+    /// [`fold_folder_with_cancel`](AsyncIterator::fold_folder_with_cancel), so the fold may run
+    /// past it by up to a block and always folds the first one. If it passes first, the fold
+    /// stops and returns [`UntilDeadline::Partial`] with the result of the items folded so far
+    /// and how many of them reached the folder, after any `filter`.
+    fn fold_folder_until<F>(
+        self,
+        folder: F,
+        deadline: Instant,
+    ) -> impl Future<Output = UntilDeadline<F::B>> + Send
+    where
+        Self: Send,
+        F: Folder<Self::Item> + Send,
+    {
+        let outcome = self.fold_folder_with_cancel(CountingFolder::new(folder), deadline);
+        async move {
+            match outcome.await {
+                Cancellable::Done(result) => UntilDeadline::Complete(result),
+                Cancellable::Cancelled(counting) => counting.into_partial(),
+            }
+        }
+    }
+
+    /// Folds the items with an initial value and operation until `deadline` passes, like
+    /// [`fold_folder_until`](AsyncIterator::fold_folder_until).
+    fn fold_until<B, F>(
+        self,
+        init: B,
+        f: F,
+        deadline: Instant,
+    ) -> impl Future<Output = UntilDeadline<B>> + Send
+    where
+        Self: Send,
+        B: Send,
+        F: FnMut(B, Self::Item) -> B + Send,
+    {
+        self.fold_folder_until(BasicFolder { init, op: f }, deadline)
+    }
 
     /// Asynchronously folds the iterator using the base folder with an initial value and operation.
-    async fn async_fold<B, F>(mut self, init: B, f: F) -> B
+    fn async_fold<B, F>(self, init: B, f: F) -> impl Future<Output = B> + Send
     where
+        Self: Send,
         Self::Item : Send,
         B: Send,
-        F: Fn(B, Self::Item) -> B + Send + Copy,
+        F: FnMut(B, Self::Item) -> B + Send,
     {
         let basic_folder = BasicFolder { init, op: f };
-        self.fold_folder(basic_folder).await
+        self.fold_folder(basic_folder)
     }
 
     /// Maps each item of the iterator using the provided operation.
@@ -99,14 +281,436 @@ pub trait AsyncIterator: Sized {
         }
     }
 
+    /// Yields the first `n` items of the iterator, like [`Iterator::take`].
+    ///
+    /// The fold stops once `n` items were taken. [`Iter::take`] shortens the slice instead.
+    fn take(self, n: usize) -> Take<Self> {
+        Take::new(self, n)
+    }
+
+    /// Skips the first `n` items of the iterator, like [`Iterator::skip`].
+    ///
+    /// [`Iter::skip`] shortens the slice instead.
+    fn skip(self, n: usize) -> Skip<Self> {
+        Skip::new(self, n)
+    }
+
+    /// Yields every `step`-th item of the iterator, starting with the first, like
+    /// [`Iterator::step_by`].
+    ///
+    /// [`Iter::step_by`] steps over the slice instead, without visiting the skipped items.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    fn step_by(self, step: usize) -> StepBy<Self> {
+        StepBy::new(self, step)
+    }
+
+    /// Yields the items of the iterator with their index, like [`Iterator::enumerate`].
+    ///
+    /// The index counts from the start of the fold, across blocks.
+    fn enumerate(self) -> Enumerate<Self> {
+        Enumerate::new(self)
+    }
+
+    /// Yields the items of the iterator while `pred` holds, like [`Iterator::take_while`].
+    ///
+    /// The fold stops at the first item failing the predicate.
+    fn take_while<P>(self, pred: P) -> TakeWhile<Self, P>
+    where P: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhile::new(self, pred)
+    }
+
+    /// Skips the items of the iterator while `pred` holds, like [`Iterator::skip_while`].
+    fn skip_while<P>(self, pred: P) -> SkipWhile<Self, P>
+    where P: FnMut(&Self::Item) -> bool,
+    {
+        SkipWhile::new(self, pred)
+    }
+
+    /// Maps the items of the iterator while `op` returns `Some`, like [`Iterator::map_while`].
+    ///
+    /// The fold stops at the first item mapped to `None`.
+    fn map_while<O, R>(self, op: O) -> MapWhile<Self, O>
+    where O: FnMut(Self::Item) -> Option<R>,
+    {
+        MapWhile::new(self, op)
+    }
+
+    /// Maps each item of the iterator to a future and yields its output.
+    ///
+    /// The futures are awaited one at a time, in order, when the driver settles a block, so
+    /// the block size and yield strategy still pace the fold.
+    fn then<O, Fut>(self, op: O) -> Then<Self, O>
+    where
+        O: FnMut(Self::Item) -> Fut,
+        Fut: Future,
+    {
+        Then::new(self, op)
+    }
+
+    /// Maps each item of the iterator using an asynchronous operation. Same as
+    /// [`then`](AsyncIterator::then).
+    fn map_async<O, Fut>(self, op: O) -> Then<Self, O>
+    where
+        O: FnMut(Self::Item) -> Fut,
+        Fut: Future,
+    {
+        self.then(op)
+    }
+
+    /// Awaits up to `n` of the futures yielded by the iterator at once, yielding their outputs
+    /// in order, like `futures::StreamExt::buffered`.
+    ///
+    /// The futures are started when the driver settles their block, and keep running while
+    /// the next blocks are folded, so up to `n` run at once whatever the block size. The fold
+    /// awaits the last ones before returning.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    fn buffered(self, n: usize) -> Buffered<Self>
+    where
+        Self::Item: Future,
+    {
+        Buffered::new(self, n, true)
+    }
+
+    /// Awaits up to `n` of the futures yielded by the iterator at once, yielding their outputs
+    /// as they complete, like `futures::StreamExt::buffer_unordered`.
+    ///
+    /// The futures are started when the driver settles their block, and keep running while
+    /// the next blocks are folded, so up to `n` run at once whatever the block size. The fold
+    /// awaits the last ones before returning.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    fn buffer_unordered(self, n: usize) -> Buffered<Self>
+    where
+        Self::Item: Future,
+    {
+        Buffered::new(self, n, false)
+    }
+
+    /// Converts the iterator into a [`Stream`](futures::Stream) pulling its items one at a
+    /// time.
+    ///
+    /// The fold runs while the stream is polled and hands it one block at a time, so at most
+    /// one block of items is buffered.
+    #[cfg(feature = "futures")]
+    fn into_stream<'a>(self) -> IntoStream<'a, Self::Item>
+    where
+        Self: Send + 'a,
+        Self::Item: Send + 'a,
+    {
+        IntoStream::new(self)
+    }
+
+    /// Filters items of the iterator based on an asynchronous predicate.
+    ///
+    /// The predicate's futures can't borrow the item. They are awaited one at a time, in
+    /// order, when the driver settles a block.
+    fn filter_async<P, Fut>(self, pred: P) -> FilterAsync<Self, P>
+    where
+        P: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        FilterAsync::new(self, pred)
+    }
+
     /// Collects the items of the iterator into a vector asynchronously.
-    async fn collect_vec(self) -> Vec<Self::Item> 
+    fn collect_vec(self) -> impl Future<Output = Vec<Self::Item>> + Send
     where
+        Self: Send,
         Self::Item : Send
     {
-        self.async_fold(Vec::new(), |mut v, e| {v.push(e); v}).await
+        self.collect()
+    }
+
+    /// Collects the items of the iterator into a collection asynchronously, like
+    /// [`Iterator::collect`].
+    ///
+    /// The collection is extended block by block. Collecting `Result<T, E>` items into a
+    /// `Result<C, E>` stops at the first `Err`: no item after it is folded, even in its block.
+    fn collect<C>(self) -> impl Future<Output = C> + Send
+    where
+        Self: Send,
+        C: FromAsyncIterator<Self::Item>,
+        C::Folder: Send,
+    {
+        self.fold_folder(C::folder())
+    }
+
+    /// Extends `collection` with the items of the iterator asynchronously and returns it.
+    ///
+    /// Works with any collection implementing [`Extend`], like `collect_into(C::default())`,
+    /// for collections that don't implement [`FromAsyncIterator`].
+    fn collect_into<C>(self, collection: C) -> impl Future<Output = C> + Send
+    where
+        Self: Send,
+        C: Extend<Self::Item> + Send,
+    {
+        self.fold_folder(ExtendFolder::new(collection))
+    }
+
+    /// Folds the items with a fallible operation, like [`Iterator::try_fold`].
+    ///
+    /// Stops at the first `Err` and returns it, without folding the next blocks.
+    fn try_fold<B, E, F>(self, init: B, f: F) -> impl Future<Output = Result<B, E>> + Send
+    where
+        Self: Send,
+        B: Send,
+        E: Send,
+        F: FnMut(B, Self::Item) -> Result<B, E> + Send,
+    {
+        self.fold_folder(TryFolder::new(init, f))
+    }
+
+    /// Calls a fallible operation on every item, like [`Iterator::try_for_each`].
+    ///
+    /// Stops at the first `Err` and returns it, without folding the next blocks.
+    fn try_for_each<E, F>(self, mut f: F) -> impl Future<Output = Result<(), E>> + Send
+    where
+        Self: Send,
+        E: Send,
+        F: FnMut(Self::Item) -> Result<(), E> + Send,
+    {
+        self.try_fold((), move |(), item| f(item))
+    }
+
+    /// Collects the `Ok` values of the items into a collection, or returns the first `Err`.
+    ///
+    /// Same as collecting into a `Result<C, E>`: stops at the first `Err`, without folding the
+    /// items after it.
+    fn try_collect<C, T, E>(self) -> impl Future<Output = Result<C, E>> + Send
+    where
+        Self: AsyncIterator<Item = Result<T, E>> + Send,
+        C: FromAsyncIterator<T>,
+        C::Folder: Send,
+        E: Send,
+    {
+        self.collect::<Result<C, E>>()
+    }
+
+    /// Checks whether any item satisfies the predicate.
+    ///
+    /// Stops folding at the end of the block holding the first match.
+    fn any<P>(self, pred: P) -> impl Future<Output = bool> + Send
+    where
+        Self: Send,
+        P: FnMut(Self::Item) -> bool + Send,
+    {
+        self.fold_folder(AnyFolder::new(pred))
+    }
+
+    /// Checks whether every item satisfies the predicate.
+    ///
+    /// Stops folding at the end of the block holding the first item that doesn't.
+    fn all<P>(self, pred: P) -> impl Future<Output = bool> + Send
+    where
+        Self: Send,
+        P: FnMut(Self::Item) -> bool + Send,
+    {
+        self.fold_folder(AllFolder::new(pred))
+    }
+
+    /// Searches for the first item satisfying the predicate.
+    ///
+    /// Stops folding at the end of the block holding the first match.
+    fn find<P>(self, pred: P) -> impl Future<Output = Option<Self::Item>> + Send
+    where
+        Self: Send,
+        Self::Item: Send,
+        P: FnMut(&Self::Item) -> bool + Send,
+    {
+        self.fold_folder(FindFolder::new(pred))
+    }
+
+    /// Applies the operation to the items and returns the first non-`None` result.
+    ///
+    /// Stops folding at the end of the block holding the first `Some`.
+    fn find_map<O, R>(self, op: O) -> impl Future<Output = Option<R>> + Send
+    where
+        Self: Send,
+        R: Send,
+        O: FnMut(Self::Item) -> Option<R> + Send,
+    {
+        self.fold_folder(FindMapFolder::new(op))
+    }
+
+    /// Searches for the index of the first item satisfying the predicate.
+    ///
+    /// Stops folding at the end of the block holding the first match.
+    fn position<P>(self, pred: P) -> impl Future<Output = Option<usize>> + Send
+    where
+        Self: Send,
+        P: FnMut(Self::Item) -> bool + Send,
+    {
+        self.fold_folder(PositionFolder::new(pred))
+    }
+
+    /// Counts the items.
+    fn count(self) -> impl Future<Output = usize> + Send
+    where
+        Self: Send,
+    {
+        self.fold_folder(CountFolder::new())
+    }
+
+    /// Sums the items, like [`Iterator::sum`].
+    ///
+    /// Every block is summed on its own before being added to the running sum, so `S` must
+    /// also be able to sum partial sums.
+    fn sum<S>(self) -> impl Future<Output = S> + Send
+    where
+        Self: Send,
+        S: Sum<Self::Item> + Sum<S> + Send,
+    {
+        self.fold_folder(SumFolder::new::<Self::Item>())
+    }
+
+    /// Multiplies the items, like [`Iterator::product`].
+    ///
+    /// Every block is multiplied on its own before being multiplied into the running product,
+    /// so `P` must also be able to multiply partial products.
+    fn product<P>(self) -> impl Future<Output = P> + Send
+    where
+        Self: Send,
+        P: Product<Self::Item> + Product<P> + Send,
+    {
+        self.fold_folder(ProductFolder::new::<Self::Item>())
+    }
+
+    /// Returns the minimum item, or the first one if several are equally minimum.
+    fn min(self) -> impl Future<Output = Option<Self::Item>> + Send
+    where
+        Self: Send,
+        Self::Item: Ord + Send,
+    {
+        self.min_by(Ord::cmp)
+    }
+
+    /// Returns the maximum item, or the last one if several are equally maximum.
+    fn max(self) -> impl Future<Output = Option<Self::Item>> + Send
+    where
+        Self: Send,
+        Self::Item: Ord + Send,
+    {
+        self.max_by(Ord::cmp)
+    }
+
+    /// Returns the minimum item with respect to the comparison function, or the first one if
+    /// several are equally minimum.
+    fn min_by<C>(self, compare: C) -> impl Future<Output = Option<Self::Item>> + Send
+    where
+        Self: Send,
+        Self::Item: Send,
+        C: FnMut(&Self::Item, &Self::Item) -> Ordering + Send,
+    {
+        self.fold_folder(MinByFolder::new(compare))
+    }
+
+    /// Returns the maximum item with respect to the comparison function, or the last one if
+    /// several are equally maximum.
+    fn max_by<C>(self, compare: C) -> impl Future<Output = Option<Self::Item>> + Send
+    where
+        Self: Send,
+        Self::Item: Send,
+        C: FnMut(&Self::Item, &Self::Item) -> Ordering + Send,
+    {
+        self.fold_folder(MaxByFolder::new(compare))
+    }
+
+    /// Returns the item with the minimum key, or the first one if several keys are equally
+    /// minimum.
+    fn min_by_key<K, F>(self, key: F) -> impl Future<Output = Option<Self::Item>> + Send
+    where
+        Self: Send,
+        Self::Item: Send,
+        K: Ord + Send,
+        F: FnMut(&Self::Item) -> K + Send,
+    {
+        self.fold_folder(MinByKeyFolder::new(key))
+    }
+
+    /// Returns the item with the maximum key, or the last one if several keys are equally
+    /// maximum.
+    fn max_by_key<K, F>(self, key: F) -> impl Future<Output = Option<Self::Item>> + Send
+    where
+        Self: Send,
+        Self::Item: Send,
+        K: Ord + Send,
+        F: FnMut(&Self::Item) -> K + Send,
+    {
+        self.fold_folder(MaxByKeyFolder::new(key))
+    }
+
+    /// Reduces the items with an associative operation, starting from `identity()`.
+    ///
+    /// Returns `identity()` if the iterator is empty.
+    fn async_reduce<ID, OP>(self, identity: ID, op: OP) -> impl Future<Output = Self::Item> + Send
+    where
+        Self: Send,
+        Self::Item: Send,
+        ID: Fn() -> Self::Item + Send,
+        OP: Fn(Self::Item, Self::Item) -> Self::Item + Send,
+    {
+        self.reduce_with(ReduceOp::new(identity, op))
+    }
+
+    /// Reduces the items with a [`Reducer`], starting from its identity.
+    fn reduce_with<R>(self, reducer: R) -> impl Future<Output = Self::Item> + Send
+    where
+        Self: Send,
+        Self::Item: Send,
+        R: Reducer<Self::Item> + Send,
+    {
+        self.fold_folder(ReduceFolder::new(reducer))
+    }
+
+}
+
+/// Conversion into an [`AsyncIterator`].
+///
+/// This is the asynchronous counterpart of [`IntoIterator`]: owned collections convert into
+/// iterators owning their items, so the resulting futures are `'static` and can be spawned,
+/// while references convert into an [`Iter`] over the borrowed elements.
+pub trait IntoAsyncIterator {
+    /// The type of items yielded by the iterator.
+    type Item;
+
+    /// The iterator this value converts into.
+    type IntoAsyncIter: AsyncIterator<Item = Self::Item>;
+
+    /// Converts the value into an asynchronous iterator.
+    fn into_async_iter(self) -> Self::IntoAsyncIter;
+}
+
+impl<I: AsyncIterator> IntoAsyncIterator for I {
+    type Item = I::Item;
+    type IntoAsyncIter = I;
+
+    fn into_async_iter(self) -> Self::IntoAsyncIter {
+        self
+    }
+}
+
+impl<'a, T: 'a + Sync> IntoAsyncIterator for &'a [T] {
+    type Item = &'a T;
+    type IntoAsyncIter = Iter<'a, T>;
+
+    fn into_async_iter(self) -> Self::IntoAsyncIter {
+        Iter::new(self)
     }
+}
 
+impl<'a, T: 'a + Sync> IntoAsyncIterator for &'a Vec<T> {
+    type Item = &'a T;
+    type IntoAsyncIter = Iter<'a, T>;
+
+    fn into_async_iter(self) -> Self::IntoAsyncIter {
+        Iter::new(self)
+    }
 }
 
 /// A trait representing a folder for an iterator.
@@ -119,6 +723,41 @@ pub trait Folder<Item> {
 
     /// Converts the folder into its final result.
     fn into_result(self) -> Self::B;
+
+    /// Returns whether the folder already knows its result, so no more items are needed.
+    ///
+    /// The drivers check it between two blocks and stop folding once it returns `true`.
+    /// Defaults to `false`.
+    fn full(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the fold was asked to stop, although the folder still wants items.
+    ///
+    /// Unlike [`full`](Folder::full), only the drivers check it, once a block is settled and
+    /// before the next one starts, so the block in progress is always folded whole. Defaults
+    /// to `false`.
+    fn cancelled(&self) -> bool {
+        false
+    }
+
+    /// Completes the asynchronous work queued while folding the last block, such as awaiting
+    /// the futures of [`then`](AsyncIterator::then).
+    ///
+    /// The drivers await it after every block, before checking [`full`](Folder::full) and
+    /// yielding. Defaults to doing nothing.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        std::future::ready(())
+    }
+
+    /// Completes the asynchronous work still in flight once the fold stops, such as the
+    /// futures [`buffered`](AsyncIterator::buffered) keeps running across blocks.
+    ///
+    /// The drivers await it once, after the last block is settled and before returning the
+    /// result or the cancelled folder. Defaults to doing nothing.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        std::future::ready(())
+    }
 }
 
 /// The base folder implementation.
@@ -139,7 +778,7 @@ pub struct BasicFolder<B, O> {
 /// - `B`: The type of the result produced by the fold operation.
 /// - `O`: The type of the folding operation.
 impl<Item, B, O> Folder<Item> for BasicFolder<B, O> where 
-O: Fn(B, Item) -> B {
+O: FnMut(B, Item) -> B {
     /// The type of result produced by the fold operation.
     type B = B;
 
@@ -151,7 +790,7 @@ O: Fn(B, Item) -> B {
     /// # Returns
     /// A new instance of `BasicFolder` with the updated initial value.
     fn fold<I: Iterator<Item=Item>>(mut self, i:I) -> Self {
-        let new_b = i.fold(self.init, &self.op);
+        let new_b = i.fold(self.init, &mut self.op);
         self.init = new_b;
         self
     }
@@ -178,11 +817,10 @@ pub struct Map<I, O> {
 /// - `R`: The type of items produced by the mapping operation.
 /// - `I`: The inner asynchronous iterator type that this `Map` iterator adapts.
 /// - `O`: The type of the mapping operation.
-#[async_trait]
 impl<R, I, O> AsyncIterator for Map<I, O> 
     where 
     I: AsyncIterator,
-    O: Fn(I::Item) -> R,
+    O: FnMut(I::Item) -> R,
     O: Send,
     I: Send 
 {
@@ -196,13 +834,29 @@ impl<R, I, O> AsyncIterator for Map<I, O>
     ///
     /// # Returns
     /// The result of folding, represented by the type `F::B`.
-    async fn fold_folder<F:Folder<Self::Item>>(self, folder:F) -> F::B
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
     where
     Self: Send,
-    F: Send
+    F: Folder<Self::Item> + Send
     {
         let map_folder = MapFolder { folder, op:self.op  };
-        self.base.fold_folder(map_folder).await
+        self.base.fold_folder(map_folder)
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<R, I, O> crate::local::LocalAsyncIterator for Map<I, O>
+where
+    I: crate::local::LocalAsyncIterator,
+    O: FnMut(I::Item) -> R,
+{
+    type Item = R;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        self.base.fold_folder(MapFolder { folder, op: self.op })
     }
 }
 
@@ -226,7 +880,7 @@ pub struct MapFolder<F, O> {
 /// - `O`: The type of the mapping operation.
 impl<Item, R, F, O> Folder<Item> for MapFolder<F, O> where
 F: Folder<R>,
-O: Fn(Item) -> R {
+O: FnMut(Item) -> R {
     /// The type of result produced by the adapted folder.
     type B = F::B;
 
@@ -238,8 +892,8 @@ O: Fn(Item) -> R {
     /// # Returns
     /// A new instance of `MapFolder` with the updated inner folder.
     fn fold<I: Iterator<Item=Item>>(mut self, i:I) -> Self {
-        let (base, op) = (self.folder, self.op);
-        let new_base_folder = base.fold(i.map(&op));
+        let (base, mut op) = (self.folder, self.op);
+        let new_base_folder = base.fold(i.map(&mut op));
         self.folder = new_base_folder;
         self.op = op;
         self
@@ -252,6 +906,26 @@ O: Fn(Item) -> R {
     fn into_result(self) -> Self::B {
         self.folder.into_result()
     }
+
+    /// Forwards to the adapted inner folder.
+    fn full(&self) -> bool {
+        self.folder.full()
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
 }
 
 /// A filter iterator that selects items based on the provided predicate.
@@ -270,7 +944,6 @@ pub struct Filter<I, P> {
 /// # Type Parameters
 /// - `I`: The inner asynchronous iterator type that this `Filter` iterator adapts.
 /// - `P`: The type of the filtering predicate.
-#[async_trait]
 impl<I, P> AsyncIterator for Filter<I, P> 
     where 
     I: AsyncIterator,
@@ -288,13 +961,29 @@ impl<I, P> AsyncIterator for Filter<I, P>
     ///
     /// # Returns
     /// The result of folding, represented by the type `F::B`.
-    async fn fold_folder<F:Folder<Self::Item>>(self, folder:F) -> F::B
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
     where
     Self: Send,
-    F: Send
+    F: Folder<Self::Item> + Send
     {
         let filter_folder = FilterFolder { folder, pred:self.pred };
-        self.base.fold_folder(filter_folder).await
+        self.base.fold_folder(filter_folder)
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<I, P> crate::local::LocalAsyncIterator for Filter<I, P>
+where
+    I: crate::local::LocalAsyncIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        self.base.fold_folder(FilterFolder { folder, pred: self.pred })
     }
 }
 
@@ -343,4 +1032,254 @@ P: FnMut(&Item) -> bool {
     fn into_result(self) -> Self::B {
         self.folder.into_result()
     }
+
+    /// Forwards to the adapted inner folder.
+    fn full(&self) -> bool {
+        self.folder.full()
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::task::yield_now;
+
+    /// A folder that sums its items and records how many blocks it was given.
+    struct BlockCounter {
+        sum: u64,
+        blocks: usize,
+    }
+
+    impl<'a> Folder<&'a u8> for BlockCounter {
+        type B = (u64, usize);
+
+        fn fold<I: Iterator<Item=&'a u8>>(mut self, i: I) -> Self {
+            self.sum = i.fold(self.sum, |acc, &x| acc + x as u64);
+            self.blocks += 1;
+            self
+        }
+
+        fn into_result(self) -> Self::B {
+            (self.sum, self.blocks)
+        }
+    }
+
+    /// Spawns a task counting how many times it got to run, yielding after every tick.
+    fn spawn_ticker() -> (Arc<AtomicUsize>, tokio::task::JoinHandle<()>) {
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let ticker = {
+            let ticks = ticks.clone();
+            tokio::spawn(async move {
+                loop {
+                    ticks.fetch_add(1, Ordering::Relaxed);
+                    yield_now().await;
+                }
+            })
+        };
+        (ticks, ticker)
+    }
+
+    /// A folder collecting items until it holds `limit` of them.
+    struct FirstN {
+        items: Vec<u32>,
+        limit: usize,
+    }
+
+    impl Folder<u32> for FirstN {
+        type B = Vec<u32>;
+
+        fn fold<I: Iterator<Item=u32>>(mut self, i: I) -> Self {
+            let room = self.limit - self.items.len();
+            self.items.extend(i.take(room));
+            self
+        }
+
+        fn into_result(self) -> Self::B {
+            self.items
+        }
+
+        fn full(&self) -> bool {
+            self.items.len() == self.limit
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fold_empty_slice() {
+        let data: Vec<i32> = Vec::new();
+        let result = Iter::new(&data).async_fold(7, |acc, &x| acc + x).await;
+        assert_eq!(result, 7);
+    }
+
+    #[tokio::test]
+    async fn test_fold_short_slice() {
+        let data: Vec<i32> = (1..=10).collect();
+        let result = Iter::new(&data).async_fold(0, |acc, &x| acc + x).await;
+        assert_eq!(result, 55);
+    }
+
+    #[tokio::test]
+    async fn test_fold_splits_into_blocks() {
+        let data = vec![1u8; 10_000];
+        let folder = BlockCounter { sum: 0, blocks: 0 };
+        let (sum, blocks) = Iter::new(&data).with_block_size(1_000).fold_folder(folder).await;
+        assert_eq!(sum, 10_000);
+        assert_eq!(blocks, 10);
+
+        let folder = BlockCounter { sum: 0, blocks: 0 };
+        let (sum, blocks) = Iter::new(&data).with_block_size(3_000).fold_folder(folder).await;
+        assert_eq!(sum, 10_000);
+        assert_eq!(blocks, 4);
+    }
+
+    #[tokio::test]
+    async fn test_fold_yields_between_blocks() {
+        let data = vec![1u8; 10_000_000];
+        let (ticks, ticker) = spawn_ticker();
+
+        let folder = BlockCounter { sum: 0, blocks: 0 };
+        let (sum, blocks) = Iter::new(&data).fold_folder(folder).await;
+        ticker.abort();
+
+        assert_eq!(sum, 10_000_000);
+        assert_eq!(blocks, 10_000_000usize.div_ceil(DEFAULT_BLOCK_SIZE));
+        // The ticker shares the current-thread runtime, so it only runs when the fold yields.
+        assert!(ticks.load(Ordering::Relaxed) >= blocks - 1);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_fold_coop_budget_yields_less() {
+        let data = vec![1u8; 1_000_000];
+        let (ticks, ticker) = spawn_ticker();
+
+        let folder = BlockCounter { sum: 0, blocks: 0 };
+        let (sum, blocks) = Iter::new(&data)
+            .with_yield_strategy(CoopBudget)
+            .fold_folder(folder)
+            .await;
+        ticker.abort();
+
+        // The task only yields once it used up its budget, which spans many blocks.
+        let ticks = ticks.load(Ordering::Relaxed);
+        assert_eq!(sum, 1_000_000);
+        assert!(ticks > 0);
+        assert!(ticks < blocks / 10);
+    }
+
+    #[tokio::test]
+    async fn test_fold_never_yield() {
+        let data = vec![1u8; 100_000];
+        let (ticks, ticker) = spawn_ticker();
+
+        let folder = BlockCounter { sum: 0, blocks: 0 };
+        let (sum, blocks) = Iter::new(&data)
+            .with_yield_strategy(NeverYield)
+            .fold_folder(folder)
+            .await;
+        ticker.abort();
+
+        assert_eq!(sum, 100_000);
+        assert!(blocks > 1);
+        assert_eq!(ticks.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fold_adaptive_block_size() {
+        let data = vec![1u8; 1_000_000];
+        let adaptive = Adaptive::new(std::time::Duration::from_micros(200));
+        let history = adaptive.history();
+        let folder = BlockCounter { sum: 0, blocks: 0 };
+        let (sum, blocks) = Iter::new(&data).with_block_size(adaptive).fold_folder(folder).await;
+
+        let sizes = history.sizes();
+        assert_eq!(sum, 1_000_000);
+        assert_eq!(sizes.len(), blocks);
+        assert_eq!(sizes.iter().sum::<usize>(), data.len());
+        assert_eq!(sizes[0], DEFAULT_BLOCK_SIZE);
+        // Summing bytes is far cheaper than 200µs per thousand elements, so blocks must grow.
+        assert!(sizes.iter().any(|&size| size > DEFAULT_BLOCK_SIZE));
+    }
+
+    #[tokio::test]
+    async fn test_full_folder_stops_between_blocks() {
+        let data: Vec<u32> = (0..1_000_000).collect();
+        let seen = AtomicUsize::new(0);
+        let count = |&x: &u32| {
+            seen.fetch_add(1, Ordering::Relaxed);
+            x
+        };
+        let folder = FirstN { items: Vec::new(), limit: 2_500 };
+        let result = Iter::new(&data).with_block_size(1_000).map(count).fold_folder(folder).await;
+
+        assert_eq!(result, (0..2_500).collect::<Vec<_>>());
+        // The folder got full in the third block, the remaining blocks were never folded.
+        assert!(seen.load(Ordering::Relaxed) <= 3_000);
+    }
+
+    #[tokio::test]
+    async fn test_full_folder_through_filter() {
+        let data: Vec<u32> = (0..1_000_000).collect();
+        let even = |x: &u32| x.is_multiple_of(2);
+        let folder = FirstN { items: Vec::new(), limit: 10 };
+        let result = from_iter(data).filter(even).fold_folder(folder).await;
+        assert_eq!(result, vec![0, 2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    }
+
+    #[tokio::test]
+    async fn test_full_folder_before_first_block() {
+        let data: Vec<u32> = (0..10).collect();
+        let folder = FirstN { items: Vec::new(), limit: 0 };
+        let result = data.into_async_iter().fold_folder(folder).await;
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_block_size_panics() {
+        let data = [1, 2, 3];
+        let _ = Iter::new(&data).with_block_size(0);
+    }
+
+    #[tokio::test]
+    async fn test_stateful_closures() {
+        let data: Vec<u32> = (1..=2_000).collect();
+        let mut running = 0;
+        let prefix_sums = Iter::new(&data)
+            .with_block_size(128)
+            .map(|&x| {
+                running += x;
+                running
+            })
+            .collect_vec()
+            .await;
+        assert_eq!(prefix_sums.len(), 2_000);
+        assert_eq!(prefix_sums[..3], [1, 3, 6]);
+        assert_eq!(prefix_sums.last(), Some(&2_001_000));
+
+        let mut calls = 0;
+        let count_calls = |acc: u32, &x: &u32| {
+            calls += 1;
+            acc.max(x)
+        };
+        let max = Iter::new(&data).with_block_size(100).async_fold(0, count_calls).await;
+        assert_eq!(max, 2_000);
+        assert_eq!(calls, 2_000);
+    }
 }
@@ -0,0 +1,182 @@
+use std::future::Future;
+use std::iter::Sum;
+
+use crate::{
+    BasicFolder, CountFolder, Enumerate, ExtendFolder, Filter, FindFolder, Folder, FromAsyncIterator,
+    Map, MapWhile, Skip, SkipWhile, StepBy, SumFolder, Take, TakeWhile,
+};
+
+/// The local variant of [`AsyncIterator`](crate::AsyncIterator), for iterators and folders
+/// that aren't `Send`, such as a `map` closure sharing its state through an `Rc`.
+///
+/// The two traits have the same methods, without the `Send` bounds here. The sources and the
+/// synchronous adapters implement both, while the adapters awaiting futures only implement
+/// [`AsyncIterator`](crate::AsyncIterator). Import only one of the two traits in a scope, or
+/// the method calls are ambiguous.
+pub trait LocalAsyncIterator: Sized {
+    /// The type of items yielded by the iterator.
+    type Item;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>;
+
+    /// Asynchronously folds the iterator using the base folder with an initial value and operation.
+    fn async_fold<B, F>(self, init: B, f: F) -> impl Future<Output = B>
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.fold_folder(BasicFolder { init, op: f })
+    }
+
+    /// Maps each item of the iterator using the provided operation.
+    fn map<O, R>(self, op: O) -> Map<Self, O>
+    where O: FnMut(Self::Item) -> R,
+    {
+        Map { base: self, op }
+    }
+
+    /// Filters items of the iterator based on the provided predicate.
+    fn filter<P>(self, pred: P) -> Filter<Self, P>
+    where P: FnMut(&Self::Item) -> bool,
+    {
+        Filter { base: self, pred }
+    }
+
+    /// Yields the first `n` items of the iterator, like [`Iterator::take`].
+    fn take(self, n: usize) -> Take<Self> {
+        Take::new(self, n)
+    }
+
+    /// Skips the first `n` items of the iterator, like [`Iterator::skip`].
+    fn skip(self, n: usize) -> Skip<Self> {
+        Skip::new(self, n)
+    }
+
+    /// Yields every `step`-th item of the iterator, starting with the first, like
+    /// [`Iterator::step_by`].
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    fn step_by(self, step: usize) -> StepBy<Self> {
+        StepBy::new(self, step)
+    }
+
+    /// Yields the items of the iterator with their index, like [`Iterator::enumerate`].
+    fn enumerate(self) -> Enumerate<Self> {
+        Enumerate::new(self)
+    }
+
+    /// Yields the items of the iterator while `pred` holds, like [`Iterator::take_while`].
+    fn take_while<P>(self, pred: P) -> TakeWhile<Self, P>
+    where P: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhile::new(self, pred)
+    }
+
+    /// Skips the items of the iterator while `pred` holds, like [`Iterator::skip_while`].
+    fn skip_while<P>(self, pred: P) -> SkipWhile<Self, P>
+    where P: FnMut(&Self::Item) -> bool,
+    {
+        SkipWhile::new(self, pred)
+    }
+
+    /// Maps the items of the iterator while `op` returns `Some`, like [`Iterator::map_while`].
+    fn map_while<O, R>(self, op: O) -> MapWhile<Self, O>
+    where O: FnMut(Self::Item) -> Option<R>,
+    {
+        MapWhile::new(self, op)
+    }
+
+    /// Collects the items of the iterator into a vector asynchronously.
+    fn collect_vec(self) -> impl Future<Output = Vec<Self::Item>> {
+        self.collect()
+    }
+
+    /// Collects the items of the iterator into a collection asynchronously, like
+    /// [`Iterator::collect`].
+    fn collect<C>(self) -> impl Future<Output = C>
+    where
+        C: FromAsyncIterator<Self::Item>,
+    {
+        self.fold_folder(C::folder())
+    }
+
+    /// Extends `collection` with the items of the iterator asynchronously and returns it.
+    fn collect_into<C>(self, collection: C) -> impl Future<Output = C>
+    where
+        C: Extend<Self::Item>,
+    {
+        self.fold_folder(ExtendFolder::new(collection))
+    }
+
+    /// Searches for the first item satisfying the predicate.
+    ///
+    /// Stops folding at the end of the block holding the first match.
+    fn find<P>(self, pred: P) -> impl Future<Output = Option<Self::Item>>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.fold_folder(FindFolder::new(pred))
+    }
+
+    /// Counts the items.
+    fn count(self) -> impl Future<Output = usize> {
+        self.fold_folder(CountFolder::new())
+    }
+
+    /// Sums the items, like [`Iterator::sum`].
+    fn sum<S>(self) -> impl Future<Output = S>
+    where
+        S: Sum<Self::Item> + Sum<S>,
+    {
+        self.fold_folder(SumFolder::new::<Self::Item>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LocalAsyncIterator;
+    use crate::{from_iter, BasicFolder, Iter, VecIter};
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    #[tokio::test]
+    async fn test_local_closures_share_rc_state() {
+        let seen = Rc::new(Cell::new(0));
+        let count = {
+            let seen = seen.clone();
+            move |x: u32| {
+                seen.set(seen.get() + 1);
+                x
+            }
+        };
+        let even = |x: &u32| x.is_multiple_of(2);
+        let sum: u32 = from_iter(0..1_000u32).with_block_size(64).map(count).filter(even).sum().await;
+        assert_eq!(sum, 249_500);
+        assert_eq!(seen.get(), 1_000);
+
+        let data: Vec<Rc<u32>> = (0..10).map(Rc::new).collect();
+        let shared = Iter::new(&data).skip(2).step_by(3).map(Rc::clone).collect_vec().await;
+        assert_eq!(shared.iter().map(|x| **x).collect::<Vec<_>>(), [2, 5, 8]);
+        assert_eq!(Rc::strong_count(&data[5]), 2);
+    }
+
+    #[tokio::test]
+    async fn test_local_folder() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let record = {
+            let log = log.clone();
+            move |acc: usize, (i, x): (usize, Rc<u8>)| {
+                log.borrow_mut().push(i);
+                acc + usize::from(*x)
+            }
+        };
+        let items = VecIter::new((0..100u8).map(Rc::new).collect()).with_block_size(16);
+        let folder = BasicFolder { init: 0, op: record };
+        let total = items.enumerate().take_while(|(i, _)| *i < 50).fold_folder(folder).await;
+        assert_eq!(total, 1_225);
+        assert_eq!(log.borrow().len(), 50);
+    }
+}
@@ -0,0 +1,393 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::driver::{drive, Blocks, Schedule};
+use crate::progress::ProgressHook;
+use crate::{AlwaysYield, AsyncIterator, BlockSize, Folder, IntoAsyncIterator, Iter, Progress, YieldStrategy};
+
+/// An asynchronous iterator owning its items, moving them out of a vector.
+///
+/// Since the iterator borrows nothing, folding it yields a `'static` future that can be
+/// handed to `tokio::spawn`.
+pub struct VecIter<T, Y = AlwaysYield> {
+    /// The items left to fold.
+    items: std::vec::IntoIter<T>,
+    /// The block size policy and yield strategy pacing the fold.
+    schedule: Schedule<Y>,
+}
+
+impl<T> VecIter<T> {
+    /// Creates an iterator moving the items out of `items`, using the
+    /// [`DEFAULT_BLOCK_SIZE`](crate::DEFAULT_BLOCK_SIZE) and yielding after every block.
+    pub fn new(items: Vec<T>) -> Self {
+        VecIter { items: items.into_iter(), schedule: Schedule::default() }
+    }
+}
+
+impl<T, Y> VecIter<T, Y> {
+    /// Sets the policy deciding how many elements are folded between two yield points.
+    ///
+    /// # Panics
+    /// Panics if a fixed `block_size` is zero.
+    pub fn with_block_size(mut self, block_size: impl Into<BlockSize>) -> Self {
+        self.schedule = self.schedule.with_block_size(block_size);
+        self
+    }
+
+    /// Returns the policy deciding how many elements are folded between two yield points.
+    pub fn block_size(&self) -> &BlockSize {
+        &self.schedule.block_size
+    }
+
+    /// Sets the strategy deciding whether to yield back to the runtime between two blocks.
+    pub fn with_yield_strategy<Y2: YieldStrategy>(self, yield_strategy: Y2) -> VecIter<T, Y2> {
+        VecIter { items: self.items, schedule: self.schedule.with_yield_strategy(yield_strategy) }
+    }
+
+    /// Sets the callback receiving the [`Progress`] of the fold at every block boundary.
+    ///
+    /// Parallel folds don't report progress.
+    pub fn with_progress(mut self, callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        self.schedule = self.schedule.with_progress(ProgressHook::new(callback));
+        self
+    }
+}
+
+impl<T> Blocks for std::vec::IntoIter<T> {
+    type Item = T;
+
+    fn fold_block<F: Folder<Self::Item>>(&mut self, folder: F, n: usize) -> (F, usize) {
+        let len = n.min(self.len());
+        (folder.fold(self.by_ref().take(len)), len)
+    }
+
+    fn is_exhausted(&mut self) -> bool {
+        self.len() == 0
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// An asynchronous iterator that moves the items out of a vector.
+///
+/// # Type Parameters
+/// - `T`: The type of items in the vector.
+/// - `Y`: The strategy deciding whether to yield between two blocks.
+impl<T: Send, Y: YieldStrategy> AsyncIterator for VecIter<T, Y> {
+    /// The type of items yielded by the iterator.
+    type Item = T;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for asynchronous folding.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        drive(self.items, self.schedule, folder)
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<T, Y: YieldStrategy> crate::local::LocalAsyncIterator for VecIter<T, Y>
+where
+{
+    type Item = T;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        drive(self.items, self.schedule, folder)
+    }
+}
+
+impl<T: Send> IntoAsyncIterator for Vec<T> {
+    type Item = T;
+    type IntoAsyncIter = VecIter<T>;
+
+    fn into_async_iter(self) -> Self::IntoAsyncIter {
+        VecIter::new(self)
+    }
+}
+
+impl<T: Send> IntoAsyncIterator for Box<[T]> {
+    type Item = T;
+    type IntoAsyncIter = VecIter<T>;
+
+    fn into_async_iter(self) -> Self::IntoAsyncIter {
+        VecIter::new(self.into_vec())
+    }
+}
+
+/// An asynchronous iterator sharing ownership of its items, yielding clones of them.
+///
+/// Like [`VecIter`], folding it yields a `'static` future. To fold references to the items
+/// instead, convert a `&Arc<[T]>` into an [`Iter`].
+pub struct ArcIter<T, Y = AlwaysYield> {
+    /// The items and the position of the next one to fold.
+    items: ArcSlice<T>,
+    /// The block size policy and yield strategy pacing the fold.
+    schedule: Schedule<Y>,
+}
+
+impl<T> ArcIter<T> {
+    /// Creates an iterator over `items`, using the
+    /// [`DEFAULT_BLOCK_SIZE`](crate::DEFAULT_BLOCK_SIZE) and yielding after every block.
+    pub fn new(items: Arc<[T]>) -> Self {
+        let end = items.len();
+        ArcIter { items: ArcSlice { items, pos: 0, end }, schedule: Schedule::default() }
+    }
+}
+
+impl<T, Y> ArcIter<T, Y> {
+    /// Sets the policy deciding how many elements are folded between two yield points.
+    ///
+    /// # Panics
+    /// Panics if a fixed `block_size` is zero.
+    pub fn with_block_size(mut self, block_size: impl Into<BlockSize>) -> Self {
+        self.schedule = self.schedule.with_block_size(block_size);
+        self
+    }
+
+    /// Returns the policy deciding how many elements are folded between two yield points.
+    pub fn block_size(&self) -> &BlockSize {
+        &self.schedule.block_size
+    }
+
+    /// Sets the strategy deciding whether to yield back to the runtime between two blocks.
+    pub fn with_yield_strategy<Y2: YieldStrategy>(self, yield_strategy: Y2) -> ArcIter<T, Y2> {
+        ArcIter { items: self.items, schedule: self.schedule.with_yield_strategy(yield_strategy) }
+    }
+
+    /// Sets the callback receiving the [`Progress`] of the fold at every block boundary.
+    ///
+    /// Parallel folds don't report progress.
+    pub fn with_progress(mut self, callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        self.schedule = self.schedule.with_progress(ProgressHook::new(callback));
+        self
+    }
+}
+
+/// A range of a shared slice.
+struct ArcSlice<T> {
+    /// The shared items.
+    items: Arc<[T]>,
+    /// The position of the next item to fold.
+    pos: usize,
+    /// The position past the last item to fold.
+    end: usize,
+}
+
+impl<T: Clone> Blocks for ArcSlice<T> {
+    type Item = T;
+
+    fn fold_block<F: Folder<Self::Item>>(&mut self, folder: F, n: usize) -> (F, usize) {
+        let end = self.pos.saturating_add(n).min(self.end);
+        let folder = folder.fold(self.items[self.pos..end].iter().cloned());
+        let len = end - self.pos;
+        self.pos = end;
+        (folder, len)
+    }
+
+    fn is_exhausted(&mut self) -> bool {
+        self.pos == self.end
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        Some(self.end - self.pos)
+    }
+}
+
+/// An asynchronous iterator that yields clones of shared items.
+///
+/// # Type Parameters
+/// - `T`: The type of items in the shared slice.
+/// - `Y`: The strategy deciding whether to yield between two blocks.
+impl<T: Clone + Send + Sync, Y: YieldStrategy> AsyncIterator for ArcIter<T, Y> {
+    /// The type of items yielded by the iterator.
+    type Item = T;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for asynchronous folding.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        drive(self.items, self.schedule, folder)
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<T: Clone, Y: YieldStrategy> crate::local::LocalAsyncIterator for ArcIter<T, Y>
+where
+{
+    type Item = T;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        drive(self.items, self.schedule, folder)
+    }
+}
+
+impl<T: Clone + Send + Sync> IntoAsyncIterator for Arc<[T]> {
+    type Item = T;
+    type IntoAsyncIter = ArcIter<T>;
+
+    fn into_async_iter(self) -> Self::IntoAsyncIter {
+        ArcIter::new(self)
+    }
+}
+
+impl<'a, T: 'a + Sync> IntoAsyncIterator for &'a Arc<[T]> {
+    type Item = &'a T;
+    type IntoAsyncIter = Iter<'a, T>;
+
+    fn into_async_iter(self) -> Self::IntoAsyncIter {
+        Iter::new(self)
+    }
+}
+
+impl<'a, T: 'a + Sync> IntoAsyncIterator for &'a Box<[T]> {
+    type Item = &'a T;
+    type IntoAsyncIter = Iter<'a, T>;
+
+    fn into_async_iter(self) -> Self::IntoAsyncIter {
+        Iter::new(self)
+    }
+}
+
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+mod parallel {
+    use super::*;
+    use crate::parallel::{fork_join, leaf_count};
+    use crate::{ParallelAsyncIterator, Reducer, SplitFolder};
+
+    impl<T, Y> VecIter<T, Y> {
+        /// Splits the iterator into consecutive parts, one per task.
+        ///
+        /// Parts are cut from the end, so every item is moved at most once.
+        fn into_leaves(self) -> Vec<Self>
+        where Y: Clone {
+            let schedule = Schedule { progress: None, ..self.schedule };
+            let mut items: Vec<T> = self.items.collect();
+            let leaf_len = items.len().div_ceil(leaf_count(items.len())).max(1);
+            let mut leaves = Vec::new();
+            while items.len() > leaf_len {
+                leaves.push(items.split_off(items.len() - leaf_len));
+            }
+            leaves.push(items);
+            leaves
+                .into_iter()
+                .rev()
+                .map(|items| VecIter { items: items.into_iter(), schedule: schedule.clone() })
+                .collect()
+        }
+    }
+
+    impl<T, Y> ParallelAsyncIterator for VecIter<T, Y>
+    where
+        T: Send + 'static,
+        Y: YieldStrategy + Clone + 'static,
+    {
+        fn par_fold_folder<B, F, R>(self, folder: F, reducer: R) -> impl Future<Output = B> + Send
+        where
+            F: SplitFolder<Self::Item, B = B> + Send + 'static,
+            B: Send + 'static,
+            R: Reducer<B> + Send + Sync + 'static,
+        {
+            fork_join(self.into_leaves(), folder, Arc::new(reducer))
+        }
+    }
+
+    impl<T, Y> ArcIter<T, Y> {
+        /// Splits the iterator into consecutive parts, one per task, sharing the same items.
+        fn into_leaves(self) -> Vec<Self>
+        where Y: Clone {
+            let ArcSlice { items, pos, end } = self.items;
+            let schedule = Schedule { progress: None, ..self.schedule };
+            let leaf_len = (end - pos).div_ceil(leaf_count(end - pos)).max(1);
+            (pos..end.max(pos + 1))
+                .step_by(leaf_len)
+                .map(|start| ArcIter {
+                    items: ArcSlice { items: items.clone(), pos: start, end: (start + leaf_len).min(end) },
+                    schedule: schedule.clone(),
+                })
+                .collect()
+        }
+    }
+
+    impl<T, Y> ParallelAsyncIterator for ArcIter<T, Y>
+    where
+        T: Clone + Send + Sync + 'static,
+        Y: YieldStrategy + Clone + 'static,
+    {
+        fn par_fold_folder<B, F, R>(self, folder: F, reducer: R) -> impl Future<Output = B> + Send
+        where
+            F: SplitFolder<Self::Item, B = B> + Send + 'static,
+            B: Send + 'static,
+            R: Reducer<B> + Send + Sync + 'static,
+        {
+            fork_join(self.into_leaves(), folder, Arc::new(reducer))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_vec_into_async_iter_is_static() {
+        let data: Vec<String> = (0..5_000).map(|i| i.to_string()).collect();
+        let handle = tokio::spawn(async move {
+            data.into_async_iter()
+                .with_block_size(100)
+                .async_fold(0, |acc, s: String| acc + s.len())
+                .await
+        });
+        let expected: usize = (0..5_000).map(|i: i32| i.to_string().len()).sum();
+        assert_eq!(handle.await.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_box_into_async_iter() {
+        let data: Box<[i32]> = (1..=100).collect();
+        let result = data.into_async_iter().with_block_size(7).collect_vec().await;
+        assert_eq!(result, (1..=100).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_arc_into_async_iter() {
+        let data: Arc<[i32]> = (1..=100).collect();
+        let shared = data.clone();
+        let handle = tokio::spawn(async move {
+            shared.into_async_iter().with_block_size(30).async_fold(0, |acc, x| acc + x).await
+        });
+        assert_eq!(handle.await.unwrap(), 5_050);
+
+        let borrowed = (&data).into_async_iter().async_fold(0, |acc, &x| acc + x).await;
+        assert_eq!(borrowed, 5_050);
+    }
+
+    #[tokio::test]
+    async fn test_empty_vec() {
+        let data: Vec<i32> = Vec::new();
+        assert_eq!(data.into_async_iter().async_fold(3, |acc, x| acc + x).await, 3);
+    }
+}
@@ -0,0 +1,404 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::driver::Schedule;
+use crate::runtime::{block_on, spawn};
+use crate::{
+    AsyncIterator, BasicFolder, Filter, FilterFolder, Folder, Iter, Map, MapFolder, ReduceFolder, ReduceOp,
+    Reducer, YieldStrategy, DEFAULT_BLOCK_SIZE,
+};
+
+/// A folder that can be split to fold several parts of the input independently.
+pub trait SplitFolder<Item>: Folder<Item> + Sized {
+    /// Creates a folder for a part of the input following the part this folder folds.
+    ///
+    /// The new folder starts from the `reducer`'s identity, so its result can be combined
+    /// with this folder's result by the `reducer`.
+    fn split_off<R: Reducer<Self::B>>(&self, reducer: &R) -> Self;
+}
+
+impl<Item, B, O> SplitFolder<Item> for BasicFolder<B, O> where
+O: FnMut(B, Item) -> B + Clone {
+    fn split_off<R: Reducer<Self::B>>(&self, reducer: &R) -> Self {
+        BasicFolder { init: reducer.identity(), op: self.op.clone() }
+    }
+}
+
+impl<Item, R, F, O> SplitFolder<Item> for MapFolder<F, O> where
+F: SplitFolder<R>,
+O: FnMut(Item) -> R + Clone {
+    fn split_off<RD: Reducer<Self::B>>(&self, reducer: &RD) -> Self {
+        MapFolder { folder: self.folder.split_off(reducer), op: self.op.clone() }
+    }
+}
+
+impl<B, R> SplitFolder<B> for ReduceFolder<B, R> where
+R: Reducer<B> + Clone {
+    fn split_off<RD: Reducer<Self::B>>(&self, reducer: &RD) -> Self {
+        ReduceFolder::with_init(reducer.identity(), self.reducer().clone())
+    }
+}
+
+impl<Item, F, P> SplitFolder<Item> for FilterFolder<F, P> where
+F: SplitFolder<Item>,
+P: FnMut(&Item) -> bool + Clone {
+    fn split_off<R: Reducer<Self::B>>(&self, reducer: &R) -> Self {
+        FilterFolder { folder: self.folder.split_off(reducer), pred: self.pred.clone() }
+    }
+}
+
+/// An asynchronous iterator whose input can be split and folded by several tasks.
+///
+/// The tasks are spawned on the tokio runtime running the fold if there is one, or on the
+/// async-std or smol global executor, depending on the enabled features.
+///
+/// Only iterators owning their input can be split, since spawned tasks must be `'static`:
+/// convert borrowed data into a `Vec<T>` or an `Arc<[T]>` first, or fold a borrowed [`Iter`]
+/// on scoped threads with [`ScopedParallelAsyncIterator`].
+pub trait ParallelAsyncIterator: AsyncIterator + Send + 'static {
+    /// Folds the iterator using the provided folder, splitting the input into tasks spread
+    /// over the runtime's worker threads.
+    ///
+    /// The input is split recursively; every part is folded block by block by its own task,
+    /// with its own folder split off `folder`, and the partial results are combined in input
+    /// order by the `reducer`.
+    fn par_fold_folder<B, F, R>(self, folder: F, reducer: R) -> impl Future<Output = B> + Send
+    where
+        F: SplitFolder<Self::Item, B = B> + Send + 'static,
+        B: Send + 'static,
+        R: Reducer<B> + Send + Sync + 'static;
+
+    /// Folds the iterator in parallel, like rayon's `fold` followed by `reduce`.
+    ///
+    /// Every task folds its part with `fold_op` starting from `identity()`, then the partial
+    /// results are combined with `reduce_op`.
+    fn par_fold<B, ID, FO, RO>(
+        self,
+        identity: ID,
+        fold_op: FO,
+        reduce_op: RO,
+    ) -> impl Future<Output = B> + Send
+    where
+        B: Send + 'static,
+        ID: Fn() -> B + Send + Sync + 'static,
+        FO: FnMut(B, Self::Item) -> B + Clone + Send + 'static,
+        RO: Fn(B, B) -> B + Send + Sync + 'static,
+    {
+        self.par_fold_with(fold_op, ReduceOp::new(identity, reduce_op))
+    }
+
+    /// Folds the iterator in parallel, combining the partial results with a [`Reducer`].
+    ///
+    /// Every task folds its part with `fold_op` starting from the `reducer`'s identity.
+    fn par_fold_with<B, FO, R>(self, fold_op: FO, reducer: R) -> impl Future<Output = B> + Send
+    where
+        B: Send + 'static,
+        FO: FnMut(B, Self::Item) -> B + Clone + Send + 'static,
+        R: Reducer<B> + Send + Sync + 'static,
+    {
+        let folder = BasicFolder { init: reducer.identity(), op: fold_op };
+        self.par_fold_folder(folder, reducer)
+    }
+}
+
+/// An asynchronous iterator over borrowed input that can be split and folded by several threads.
+///
+/// Tasks spawned on a runtime must be `'static`, so they can't borrow the input. These folds
+/// run every part on a scoped thread of its own instead, driving its fold to completion there,
+/// and block the calling thread until every part is folded. Neither the input nor the closures
+/// need to be `'static`, so a fold can borrow a huge slice and the caller's state without
+/// copying anything. Inside an async task, call them through `tokio::task::block_in_place` or
+/// [`spawn_blocking`](crate::spawn_blocking) so the worker isn't stalled.
+///
+/// Implemented for [`Iter`], and for [`map`](AsyncIterator::map) and
+/// [`filter`](AsyncIterator::filter) over it.
+pub trait ScopedParallelAsyncIterator: AsyncIterator + Send {
+    /// Folds the iterator using the provided folder, splitting the input into parts folded on
+    /// scoped threads, one per available core.
+    ///
+    /// Every part is folded block by block with its own folder split off `folder`, and the
+    /// partial results are combined in input order by the `reducer`.
+    fn par_fold_folder_blocking<B, F, R>(self, folder: F, reducer: R) -> B
+    where
+        F: SplitFolder<Self::Item, B = B> + Send,
+        B: Send,
+        R: Reducer<B> + Sync;
+
+    /// Folds the iterator in parallel on scoped threads, like
+    /// [`par_fold`](ParallelAsyncIterator::par_fold).
+    fn par_fold_blocking<B, ID, FO, RO>(self, identity: ID, fold_op: FO, reduce_op: RO) -> B
+    where
+        B: Send,
+        ID: Fn() -> B + Sync,
+        FO: FnMut(B, Self::Item) -> B + Clone + Send,
+        RO: Fn(B, B) -> B + Sync,
+    {
+        self.par_fold_with_blocking(fold_op, ReduceOp::new(identity, reduce_op))
+    }
+
+    /// Folds the iterator in parallel on scoped threads, combining the partial results with a
+    /// [`Reducer`].
+    fn par_fold_with_blocking<B, FO, R>(self, fold_op: FO, reducer: R) -> B
+    where
+        B: Send,
+        FO: FnMut(B, Self::Item) -> B + Clone + Send,
+        R: Reducer<B> + Sync,
+    {
+        let folder = BasicFolder { init: reducer.identity(), op: fold_op };
+        self.par_fold_folder_blocking(folder, reducer)
+    }
+}
+
+/// The smallest number of items worth a task of its own.
+const MIN_LEAF_LEN: usize = DEFAULT_BLOCK_SIZE * 16;
+
+/// Returns how many parts to split an input of `len` items into.
+pub(crate) fn leaf_count(len: usize) -> usize {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    (len / MIN_LEAF_LEN).clamp(1, threads * 4)
+}
+
+/// Folds every leaf in a task of its own, splitting `leaves` recursively, and combines the
+/// results in order.
+pub(crate) fn fork_join<S, F, R>(
+    mut leaves: Vec<S>,
+    folder: F,
+    reducer: Arc<R>,
+) -> Pin<Box<dyn Future<Output = F::B> + Send>>
+where
+    S: AsyncIterator + Send + 'static,
+    F: SplitFolder<S::Item> + Send + 'static,
+    F::B: Send + 'static,
+    R: Reducer<F::B> + Send + Sync + 'static,
+{
+    Box::pin(async move {
+        if leaves.len() <= 1 {
+            return match leaves.pop() {
+                Some(leaf) => leaf.fold_folder(folder).await,
+                None => folder.into_result(),
+            };
+        }
+
+        let right = leaves.split_off(leaves.len() / 2);
+        let right = spawn(fork_join(right, folder.split_off(&*reducer), reducer.clone()));
+        let left = fork_join(leaves, folder, reducer.clone()).await;
+        reducer.reduce(left, right.await)
+    })
+}
+
+/// Returns how many parts to split an input of `len` items into when every part gets a thread.
+fn scoped_leaf_count(len: usize) -> usize {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    (len / MIN_LEAF_LEN).clamp(1, threads)
+}
+
+/// Folds every leaf on a scoped thread of its own, splitting `leaves` recursively, and combines
+/// the results in order.
+fn scoped_fork_join<S, F, R>(mut leaves: Vec<S>, folder: F, reducer: &R) -> F::B
+where
+    S: AsyncIterator + Send,
+    F: SplitFolder<S::Item> + Send,
+    F::B: Send,
+    R: Reducer<F::B> + Sync,
+{
+    if leaves.len() <= 1 {
+        return match leaves.pop() {
+            Some(leaf) => block_on(leaf.fold_folder(folder)),
+            None => folder.into_result(),
+        };
+    }
+
+    let right = leaves.split_off(leaves.len() / 2);
+    let right_folder = folder.split_off(reducer);
+    std::thread::scope(|scope| {
+        let right = scope.spawn(|| scoped_fork_join(right, right_folder, reducer));
+        let left = scoped_fork_join(leaves, folder, reducer);
+        match right.join() {
+            Ok(right) => reducer.reduce(left, right),
+            Err(e) => std::panic::resume_unwind(e),
+        }
+    })
+}
+
+impl<'a, T, Y> Iter<'a, T, Y> {
+    /// Splits the iterator into consecutive parts of the same slice, one per thread.
+    fn into_leaves(self) -> Vec<Self>
+    where Y: Clone {
+        let (slice, schedule) = self.into_parts();
+        let schedule = Schedule { progress: None, ..schedule };
+        let leaf_len = slice.len().div_ceil(scoped_leaf_count(slice.len())).max(1);
+        slice.chunks(leaf_len).map(|part| Iter { slice: part, schedule: schedule.clone() }).collect()
+    }
+}
+
+impl<'a, T, Y> ScopedParallelAsyncIterator for Iter<'a, T, Y>
+where
+    T: 'a + Sync,
+    Y: YieldStrategy + Clone,
+{
+    fn par_fold_folder_blocking<B, F, R>(self, folder: F, reducer: R) -> B
+    where
+        F: SplitFolder<Self::Item, B = B> + Send,
+        B: Send,
+        R: Reducer<B> + Sync,
+    {
+        scoped_fork_join(self.into_leaves(), folder, &reducer)
+    }
+}
+
+impl<R, I, O> ScopedParallelAsyncIterator for Map<I, O>
+where
+    I: ScopedParallelAsyncIterator,
+    O: FnMut(I::Item) -> R + Clone + Send,
+{
+    fn par_fold_folder_blocking<B, F, RD>(self, folder: F, reducer: RD) -> B
+    where
+        F: SplitFolder<Self::Item, B = B> + Send,
+        B: Send,
+        RD: Reducer<B> + Sync,
+    {
+        let map_folder = MapFolder { folder, op: self.op };
+        self.base.par_fold_folder_blocking(map_folder, reducer)
+    }
+}
+
+impl<I, P> ScopedParallelAsyncIterator for Filter<I, P>
+where
+    I: ScopedParallelAsyncIterator,
+    P: FnMut(&I::Item) -> bool + Clone + Send,
+{
+    fn par_fold_folder_blocking<B, F, R>(self, folder: F, reducer: R) -> B
+    where
+        F: SplitFolder<Self::Item, B = B> + Send,
+        B: Send,
+        R: Reducer<B> + Sync,
+    {
+        let filter_folder = FilterFolder { folder, pred: self.pred };
+        self.base.par_fold_folder_blocking(filter_folder, reducer)
+    }
+}
+
+impl<R, I, O> ParallelAsyncIterator for Map<I, O>
+where
+    I: ParallelAsyncIterator,
+    O: FnMut(I::Item) -> R + Clone + Send + 'static,
+{
+    fn par_fold_folder<B, F, RD>(self, folder: F, reducer: RD) -> impl Future<Output = B> + Send
+    where
+        F: SplitFolder<Self::Item, B = B> + Send + 'static,
+        B: Send + 'static,
+        RD: Reducer<B> + Send + Sync + 'static,
+    {
+        let map_folder = MapFolder { folder, op: self.op };
+        self.base.par_fold_folder(map_folder, reducer)
+    }
+}
+
+impl<I, P> ParallelAsyncIterator for Filter<I, P>
+where
+    I: ParallelAsyncIterator,
+    P: FnMut(&I::Item) -> bool + Clone + Send + 'static,
+{
+    fn par_fold_folder<B, F, R>(self, folder: F, reducer: R) -> impl Future<Output = B> + Send
+    where
+        F: SplitFolder<Self::Item, B = B> + Send + 'static,
+        B: Send + 'static,
+        R: Reducer<B> + Send + Sync + 'static,
+    {
+        let filter_folder = FilterFolder { folder, pred: self.pred };
+        self.base.par_fold_folder(filter_folder, reducer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoAsyncIterator;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use std::thread::ThreadId;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_par_fold_uses_several_workers() {
+        let data: Arc<[u64]> = (0..4_000_000).collect();
+        let threads = Arc::new(Mutex::new(HashSet::<ThreadId>::new()));
+        let record = {
+            let threads = threads.clone();
+            move |x: u64| {
+                threads.lock().unwrap().insert(std::thread::current().id());
+                x * 2
+            }
+        };
+        let sum = data
+            .into_async_iter()
+            .map(record)
+            .par_fold(|| 0u64, |acc, x| acc + x, |a, b| a + b)
+            .await;
+
+        assert_eq!(sum, 3_999_999 * 4_000_000);
+        assert!(threads.lock().unwrap().len() > 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_par_fold_keeps_input_order() {
+        let data: Vec<u32> = (0..1_000_000).collect();
+        let odd = |x: &u32| x % 2 == 1;
+        let result = data
+            .clone()
+            .into_async_iter()
+            .filter(odd)
+            .par_fold(Vec::new, |mut v, x| { v.push(x); v }, |mut a, b| { a.extend(b); a })
+            .await;
+        assert_eq!(result, data.into_iter().filter(odd).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_par_fold_folder_keeps_init() {
+        let data: Vec<u64> = (1..=100_000).collect();
+        let folder = BasicFolder { init: 1_000, op: |acc: u64, x: u64| acc + x };
+        let sum = data
+            .into_async_iter()
+            .par_fold_folder(folder, ReduceOp::new(|| 0, |a, b| a + b))
+            .await;
+        assert_eq!(sum, 1_000 + 5_000_050_000);
+    }
+
+    #[tokio::test]
+    async fn test_par_fold_empty() {
+        let data: Vec<u64> = Vec::new();
+        let sum = data.into_async_iter().par_fold(|| 7, |acc, x| acc + x, |a, b| a + b).await;
+        assert_eq!(sum, 7);
+    }
+
+    #[test]
+    fn test_par_fold_blocking_borrows_the_slice() {
+        let data: Vec<u64> = (0..4_000_000).collect();
+        let threads = Mutex::new(HashSet::<ThreadId>::new());
+        // The closures borrow the caller's state, which spawned tasks couldn't.
+        let record = |x: &u64| {
+            threads.lock().unwrap().insert(std::thread::current().id());
+            x * 2
+        };
+        let sum = Iter::new(&data).map(record).par_fold_blocking(|| 0u64, |acc, x| acc + x, |a, b| a + b);
+
+        assert_eq!(sum, 3_999_999 * 4_000_000);
+        let parallelism = std::thread::available_parallelism().map_or(1, |n| n.get());
+        assert_eq!(threads.lock().unwrap().len() > 1, parallelism > 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_par_fold_blocking_keeps_input_order() {
+        let data: Vec<u32> = (0..1_000_000).collect();
+        let odd = |x: &&u32| *x % 2 == 1;
+        let result = tokio::task::block_in_place(|| {
+            Iter::new(&data)
+                .filter(odd)
+                .par_fold_blocking(Vec::new, |mut v, &x| { v.push(x); v }, |mut a, b| { a.extend(b); a })
+        });
+        assert_eq!(result, data.iter().filter(odd).copied().collect::<Vec<_>>());
+
+        let empty: [u64; 0] = [];
+        assert_eq!(Iter::new(&empty).par_fold_blocking(|| 7, |acc, &x| acc + x, |a, b| a + b), 7);
+    }
+}
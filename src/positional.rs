@@ -0,0 +1,608 @@
+use std::future::Future;
+
+use crate::driver::{drive, Blocks, Schedule};
+use crate::{AsyncIterator, Folder, Iter, YieldStrategy};
+
+/// An iterator yielding the first items of the base iterator, like [`std::iter::Take`].
+pub struct Take<I> {
+    /// The base iterator.
+    base: I,
+    /// The number of items to yield.
+    n: usize,
+}
+
+impl<I> Take<I> {
+    /// Creates an iterator yielding the first `n` items of `base`.
+    pub(crate) fn new(base: I, n: usize) -> Self {
+        Take { base, n }
+    }
+}
+
+/// An asynchronous iterator that yields the first items of another one.
+///
+/// # Type Parameters
+/// - `I`: The inner asynchronous iterator type that this `Take` iterator adapts.
+impl<I: AsyncIterator + Send> AsyncIterator for Take<I> {
+    /// The type of items yielded by the iterator.
+    type Item = I::Item;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for folding the items.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        self.base.fold_folder(TakeFolder { folder, left: self.n })
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<I> crate::local::LocalAsyncIterator for Take<I>
+where
+    I: crate::local::LocalAsyncIterator,
+{
+    type Item = I::Item;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        self.base.fold_folder(TakeFolder { folder, left: self.n })
+    }
+}
+
+/// A folder for the take iterator.
+///
+/// Gets [full](Folder::full) once it took enough items, so the driver stops the fold.
+pub struct TakeFolder<F> {
+    /// The inner folder.
+    folder: F,
+    /// The number of items left to take.
+    left: usize,
+}
+
+impl<Item, F> Folder<Item> for TakeFolder<F> where
+F: Folder<Item> {
+    type B = F::B;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let left = &mut self.left;
+        self.folder = self.folder.fold(i.take(*left).inspect(|_| *left -= 1));
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.into_result()
+    }
+
+    fn full(&self) -> bool {
+        self.left == 0 || self.folder.full()
+    }
+
+    /// Forwards to the inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    /// Forwards to the inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+/// An iterator skipping the first items of the base iterator, like [`std::iter::Skip`].
+pub struct Skip<I> {
+    /// The base iterator.
+    base: I,
+    /// The number of items to skip.
+    n: usize,
+}
+
+impl<I> Skip<I> {
+    /// Creates an iterator skipping the first `n` items of `base`.
+    pub(crate) fn new(base: I, n: usize) -> Self {
+        Skip { base, n }
+    }
+}
+
+/// An asynchronous iterator that skips the first items of another one.
+///
+/// # Type Parameters
+/// - `I`: The inner asynchronous iterator type that this `Skip` iterator adapts.
+impl<I: AsyncIterator + Send> AsyncIterator for Skip<I> {
+    /// The type of items yielded by the iterator.
+    type Item = I::Item;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for folding the items.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        self.base.fold_folder(SkipFolder { folder, left: self.n })
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<I> crate::local::LocalAsyncIterator for Skip<I>
+where
+    I: crate::local::LocalAsyncIterator,
+{
+    type Item = I::Item;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        self.base.fold_folder(SkipFolder { folder, left: self.n })
+    }
+}
+
+/// A folder for the skip iterator.
+pub struct SkipFolder<F> {
+    /// The inner folder.
+    folder: F,
+    /// The number of items left to skip.
+    left: usize,
+}
+
+impl<Item, F> Folder<Item> for SkipFolder<F> where
+F: Folder<Item> {
+    type B = F::B;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, mut i: I) -> Self {
+        while self.left > 0 {
+            if i.next().is_none() {
+                return self;
+            }
+            self.left -= 1;
+        }
+        self.folder = self.folder.fold(i);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.into_result()
+    }
+
+    /// Forwards to the inner folder.
+    fn full(&self) -> bool {
+        self.folder.full()
+    }
+
+    /// Forwards to the inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    /// Forwards to the inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+/// An iterator yielding every `step`-th item of the base iterator, like
+/// [`std::iter::StepBy`].
+pub struct StepBy<I> {
+    /// The base iterator.
+    base: I,
+    /// The distance between two yielded items.
+    step: usize,
+}
+
+impl<I> StepBy<I> {
+    /// Creates an iterator yielding every `step`-th item of `base`, starting with the first.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    pub(crate) fn new(base: I, step: usize) -> Self {
+        assert!(step > 0, "step must be at least 1");
+        StepBy { base, step }
+    }
+}
+
+/// An asynchronous iterator that yields every `step`-th item of another one.
+///
+/// # Type Parameters
+/// - `I`: The inner asynchronous iterator type that this `StepBy` iterator adapts.
+impl<I: AsyncIterator + Send> AsyncIterator for StepBy<I> {
+    /// The type of items yielded by the iterator.
+    type Item = I::Item;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for folding the items.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        self.base.fold_folder(StepByFolder { folder, step: self.step, skip: 0 })
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<I> crate::local::LocalAsyncIterator for StepBy<I>
+where
+    I: crate::local::LocalAsyncIterator,
+{
+    type Item = I::Item;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        self.base.fold_folder(StepByFolder { folder, step: self.step, skip: 0 })
+    }
+}
+
+/// A folder for the step-by iterator.
+///
+/// Keeps how many items to skip before the next one, so the steps carry over block
+/// boundaries.
+pub struct StepByFolder<F> {
+    /// The inner folder.
+    folder: F,
+    /// The distance between two yielded items.
+    step: usize,
+    /// The number of items to skip before the next yielded one.
+    skip: usize,
+}
+
+impl<Item, F> Folder<Item> for StepByFolder<F> where
+F: Folder<Item> {
+    type B = F::B;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let (step, skip) = (self.step, &mut self.skip);
+        let stepped = i.filter(|_| match *skip {
+            0 => {
+                *skip = step - 1;
+                true
+            }
+            _ => {
+                *skip -= 1;
+                false
+            }
+        });
+        self.folder = self.folder.fold(stepped);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.into_result()
+    }
+
+    /// Forwards to the inner folder.
+    fn full(&self) -> bool {
+        self.folder.full()
+    }
+
+    /// Forwards to the inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    /// Forwards to the inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+/// An iterator yielding the items of the base iterator with their index, like
+/// [`std::iter::Enumerate`].
+pub struct Enumerate<I> {
+    /// The base iterator.
+    base: I,
+}
+
+impl<I> Enumerate<I> {
+    /// Creates an iterator yielding the items of `base` with their index.
+    pub(crate) fn new(base: I) -> Self {
+        Enumerate { base }
+    }
+}
+
+/// An asynchronous iterator that yields the items of another one with their index.
+///
+/// # Type Parameters
+/// - `I`: The inner asynchronous iterator type that this `Enumerate` iterator adapts.
+impl<I: AsyncIterator + Send> AsyncIterator for Enumerate<I> {
+    /// The type of items yielded by the iterator.
+    type Item = (usize, I::Item);
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for folding the items.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        self.base.fold_folder(EnumerateFolder { folder, index: 0 })
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<I> crate::local::LocalAsyncIterator for Enumerate<I>
+where
+    I: crate::local::LocalAsyncIterator,
+{
+    type Item = (usize, I::Item);
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        self.base.fold_folder(EnumerateFolder { folder, index: 0 })
+    }
+}
+
+/// A folder for the enumerate iterator.
+///
+/// Keeps the index of the next item, so indices carry over block boundaries.
+pub struct EnumerateFolder<F> {
+    /// The inner folder.
+    folder: F,
+    /// The index of the next item.
+    index: usize,
+}
+
+impl<Item, F> Folder<Item> for EnumerateFolder<F> where
+F: Folder<(usize, Item)> {
+    type B = F::B;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        let index = &mut self.index;
+        let enumerated = i.map(|item| {
+            *index += 1;
+            (*index - 1, item)
+        });
+        self.folder = self.folder.fold(enumerated);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.into_result()
+    }
+
+    /// Forwards to the inner folder.
+    fn full(&self) -> bool {
+        self.folder.full()
+    }
+
+    /// Forwards to the inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.cancelled()
+    }
+
+    /// Forwards to the inner folder.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.settle()
+    }
+
+    /// Forwards to the inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.flush()
+    }
+}
+
+/// An asynchronous iterator over every `step`-th item of a slice, created by
+/// [`Iter::step_by`].
+///
+/// The steps are taken on the slice, so the skipped items are never visited.
+pub struct StridedIter<'a, T, Y> {
+    /// The items left to fold, in steps of `step`.
+    items: Strided<'a, T>,
+    /// The block size policy and yield strategy pacing the fold.
+    schedule: Schedule<Y>,
+}
+
+impl<'a, T, Y> StridedIter<'a, T, Y> {
+    /// Creates an iterator over every `step`-th item of `slice`, starting with the first.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    pub(crate) fn new(slice: &'a [T], step: usize, schedule: Schedule<Y>) -> Self {
+        assert!(step > 0, "step must be at least 1");
+        StridedIter { items: Strided { slice, step }, schedule }
+    }
+}
+
+/// A slice folded in steps.
+struct Strided<'a, T> {
+    /// The items left to fold, starting with the next one to yield.
+    slice: &'a [T],
+    /// The distance between two yielded items.
+    step: usize,
+}
+
+impl<'a, T> Blocks for Strided<'a, T> {
+    type Item = &'a T;
+
+    fn fold_block<F: Folder<Self::Item>>(&mut self, folder: F, n: usize) -> (F, usize) {
+        let len = n.min(self.slice.len().div_ceil(self.step));
+        let (block, rest) = self.slice.split_at(len.saturating_mul(self.step).min(self.slice.len()));
+        self.slice = rest;
+        (folder.fold(block.iter().step_by(self.step)), len)
+    }
+
+    fn is_exhausted(&mut self) -> bool {
+        self.slice.is_empty()
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        Some(self.slice.len().div_ceil(self.step))
+    }
+}
+
+/// An asynchronous iterator over every `step`-th item of a slice.
+///
+/// # Type Parameters
+/// - `T`: The type of items in the slice.
+/// - `Y`: The strategy deciding whether to yield between two blocks.
+impl<'a, T: 'a + Sync, Y: YieldStrategy> AsyncIterator for StridedIter<'a, T, Y> {
+    /// The type of items yielded by the iterator.
+    type Item = &'a T;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for asynchronous folding.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        drive(self.items, self.schedule, folder)
+    }
+}
+
+/// The local variant of the [`AsyncIterator`] implementation above, without the `Send` bounds.
+impl<'a, T: 'a, Y: YieldStrategy> crate::local::LocalAsyncIterator for StridedIter<'a, T, Y>
+where
+{
+    type Item = &'a T;
+
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B>
+    where
+    F: Folder<Self::Item>
+    {
+        drive(self.items, self.schedule, folder)
+    }
+}
+
+impl<'a, T: 'a, Y> Iter<'a, T, Y> {
+    /// Creates an iterator over the first `n` items, like [`Iterator::take`].
+    ///
+    /// Shortens the slice in O(1), so the result is still an [`Iter`], and takes precedence over
+    /// [`AsyncIterator::take`].
+    pub fn take(self, n: usize) -> Self {
+        let (slice, schedule) = self.into_parts();
+        Iter { slice: &slice[..n.min(slice.len())], schedule }
+    }
+
+    /// Creates an iterator skipping the first `n` items, like [`Iterator::skip`].
+    ///
+    /// Shortens the slice in O(1), so the result is still an [`Iter`], and takes precedence over
+    /// [`AsyncIterator::skip`].
+    pub fn skip(self, n: usize) -> Self {
+        let (slice, schedule) = self.into_parts();
+        Iter { slice: &slice[n.min(slice.len())..], schedule }
+    }
+
+    /// Creates an iterator over every `step`-th item, starting with the first, like
+    /// [`Iterator::step_by`].
+    ///
+    /// Steps over the slice without visiting the skipped items, and takes precedence over
+    /// [`AsyncIterator::step_by`]. Every block holds up to the block size of yielded items.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    pub fn step_by(self, step: usize) -> StridedIter<'a, T, Y> {
+        let (slice, schedule) = self.into_parts();
+        StridedIter::new(slice, step, schedule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_iter, NeverYield};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_slice_take_skip_step_by() {
+        let data: Vec<u32> = (0..10_000).collect();
+        let iter = Iter::new(&data).with_block_size(64).skip(100).take(1_000);
+        assert_eq!(iter.slice, &data[100..1_100]);
+        assert_eq!(iter.sum::<u32>().await, (100..1_100).sum());
+        assert!(Iter::new(&data).skip(20_000).take(5).collect_vec().await.is_empty());
+
+        let stepped = Iter::new(&data).with_block_size(7).skip(3).step_by(1_000).collect_vec().await;
+        let expected: Vec<&u32> = data.iter().skip(3).step_by(1_000).collect();
+        assert_eq!(stepped, expected);
+        for (len, step) in [(0, 3), (1, 1), (10, 3), (12, 3), (1_000, 999)] {
+            let stepped = Iter::new(&data[..len]).with_block_size(2).step_by(step).collect_vec().await;
+            assert_eq!(stepped, data[..len].iter().step_by(step).collect::<Vec<_>>());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_adapters_carry_over_blocks() {
+        let data: Vec<u32> = (0..1_000).collect();
+        let double = |x: &u32| x * 2;
+        let expected: Vec<u32> = data.iter().map(double).skip(5).step_by(3).take(100).collect();
+        let result = Iter::new(&data)
+            .with_block_size(7)
+            .map(double)
+            .skip(5)
+            .step_by(3)
+            .take(100)
+            .collect_vec()
+            .await;
+        assert_eq!(result, expected);
+
+        let evens = from_iter(0..1_000u32).with_block_size(10).filter(|x| x.is_multiple_of(2));
+        let indexed = evens.enumerate().skip(495).collect_vec().await;
+        assert_eq!(indexed, [(495, 990), (496, 992), (497, 994), (498, 996), (499, 998)]);
+    }
+
+    #[tokio::test]
+    async fn test_take_stops_the_fold() {
+        let data: Vec<u32> = (0..100_000).collect();
+        let seen = AtomicUsize::new(0);
+        let count = |x: &u32| {
+            seen.fetch_add(1, Ordering::Relaxed);
+            *x
+        };
+        let taken = Iter::new(&data)
+            .with_block_size(100)
+            .with_yield_strategy(NeverYield)
+            .map(count)
+            .take(250)
+            .count()
+            .await;
+        assert_eq!(taken, 250);
+        // The items past the 250th are never mapped.
+        assert_eq!(seen.load(Ordering::Relaxed), 250);
+    }
+}
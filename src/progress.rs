@@ -0,0 +1,144 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The progress of a fold, reported by the driver at every block boundary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// The number of elements folded so far.
+    pub elements: usize,
+    /// The number of blocks folded so far.
+    pub blocks: usize,
+    /// The time elapsed since the fold started.
+    pub elapsed: Duration,
+    /// The estimated time left, if the source knows how many elements are left.
+    ///
+    /// Extrapolated from the time per element so far.
+    pub remaining: Option<Duration>,
+}
+
+/// A shared callback receiving the progress of a fold.
+#[derive(Clone)]
+pub(crate) struct ProgressHook(Arc<dyn Fn(Progress) + Send + Sync>);
+
+impl ProgressHook {
+    /// Wraps `callback` so it can be shared by the clones of a schedule.
+    pub(crate) fn new(callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        ProgressHook(Arc::new(callback))
+    }
+}
+
+impl fmt::Debug for ProgressHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressHook")
+    }
+}
+
+/// The progress of a running fold, reported to a [`ProgressHook`].
+pub(crate) struct ProgressTracker {
+    /// The callback to report to.
+    hook: ProgressHook,
+    /// When the fold started.
+    started: Instant,
+    /// The number of elements folded so far.
+    elements: usize,
+    /// The number of blocks folded so far.
+    blocks: usize,
+}
+
+impl ProgressTracker {
+    /// Starts tracking a fold reporting to `hook`.
+    pub(crate) fn start(hook: ProgressHook) -> Self {
+        ProgressTracker { hook, started: Instant::now(), elements: 0, blocks: 0 }
+    }
+
+    /// Records a block of `len` elements and reports the progress, given the number of
+    /// elements left to fold if known.
+    pub(crate) fn finish_block(&mut self, len: usize, left: Option<usize>) {
+        self.elements += len;
+        self.blocks += 1;
+        let elapsed = self.started.elapsed();
+        let remaining = match left {
+            Some(0) => Some(Duration::ZERO),
+            Some(left) if self.elements > 0 => {
+                Some(elapsed.mul_f64(left as f64 / self.elements as f64))
+            }
+            _ => None,
+        };
+        (self.hook.0)(Progress { elements: self.elements, blocks: self.blocks, elapsed, remaining });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_iter, AsyncIterator, IntoAsyncIterator, Iter};
+    use std::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_progress_at_every_block() {
+        let data: Vec<u64> = (0..10_000).collect();
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let record = {
+            let reports = reports.clone();
+            move |progress| reports.lock().unwrap().push(progress)
+        };
+        let sum = Iter::new(&data)
+            .with_block_size(3_000)
+            .with_progress(record)
+            .filter(|&&x| x.is_multiple_of(2))
+            .async_fold(0, |acc, &x| acc + x)
+            .await;
+        assert_eq!(sum, 24_995_000);
+
+        let reports = reports.lock().unwrap();
+        // The elements are counted at the source, before the filter.
+        let elements: Vec<usize> = reports.iter().map(|p| p.elements).collect();
+        assert_eq!(elements, [3_000, 6_000, 9_000, 10_000]);
+        let blocks: Vec<usize> = reports.iter().map(|p| p.blocks).collect();
+        assert_eq!(blocks, [1, 2, 3, 4]);
+        assert!(reports.windows(2).all(|w| w[0].elapsed <= w[1].elapsed));
+        assert!(reports.iter().all(|p| p.remaining.is_some()));
+        assert_eq!(reports[3].remaining, Some(Duration::ZERO));
+    }
+
+    #[tokio::test]
+    async fn test_progress_through_watch_channel() {
+        let (sender, mut receiver) = tokio::sync::watch::channel(None);
+        let data: Vec<u32> = (0..1_000).collect();
+        let fold = data
+            .into_async_iter()
+            .with_block_size(100)
+            .with_progress(move |progress| {
+                sender.send_replace(Some(progress));
+            })
+            .async_fold(0, |acc, x| acc + x);
+        let handle = tokio::spawn(fold);
+
+        let mut last = None;
+        while receiver.changed().await.is_ok() {
+            let progress = receiver.borrow_and_update().unwrap();
+            if let Some(Progress { elements, .. }) = last {
+                assert!(progress.elements > elements);
+            }
+            last = Some(progress);
+        }
+        assert_eq!(handle.await.unwrap(), 499_500);
+        let last = last.unwrap();
+        assert_eq!((last.elements, last.blocks), (1_000, 10));
+    }
+
+    #[tokio::test]
+    async fn test_progress_of_unsized_source() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let record = {
+            let reports = reports.clone();
+            move |progress: Progress| reports.lock().unwrap().push(progress.remaining)
+        };
+        let evens = (0..1_000u32).filter(|x| x.is_multiple_of(2));
+        let count = from_iter(evens).with_block_size(200).with_progress(record).count().await;
+        assert_eq!(count, 500);
+        // A filtered iterator doesn't know how many items are left, until it runs out.
+        assert_eq!(*reports.lock().unwrap(), [None, None, Some(Duration::ZERO)]);
+    }
+}
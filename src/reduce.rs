@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::iter::Sum;
+
+use crate::Folder;
+
+/// Combines the results of two folders that folded adjacent parts of the input.
+///
+/// `reduce` must be associative and `identity` must be neutral for it, so that splitting the
+/// input anywhere gives the same result as folding it in one go.
+pub trait Reducer<B> {
+    /// Returns the result of folding no items.
+    fn identity(&self) -> B;
+
+    /// Combines the result of a part of the input with the result of the part following it.
+    fn reduce(&self, left: B, right: B) -> B;
+}
+
+/// A reducer built from an identity function and a combining operation.
+#[derive(Clone, Copy, Debug)]
+pub struct ReduceOp<ID, OP> {
+    /// The function returning the result of folding no items.
+    identity: ID,
+    /// The associative combining operation.
+    op: OP,
+}
+
+impl<ID, OP> ReduceOp<ID, OP> {
+    /// Creates a reducer combining results with `op`, starting from `identity()`.
+    pub fn new(identity: ID, op: OP) -> Self {
+        ReduceOp { identity, op }
+    }
+}
+
+impl<B, ID, OP> Reducer<B> for ReduceOp<ID, OP> where
+ID: Fn() -> B,
+OP: Fn(B, B) -> B {
+    fn identity(&self) -> B {
+        (self.identity)()
+    }
+
+    fn reduce(&self, left: B, right: B) -> B {
+        (self.op)(left, right)
+    }
+}
+
+/// A reducer adding partial sums, starting from the sum of no items.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SumReducer;
+
+impl<S: Sum<S>> Reducer<S> for SumReducer {
+    fn identity(&self) -> S {
+        std::iter::empty::<S>().sum()
+    }
+
+    fn reduce(&self, left: S, right: S) -> S {
+        [left, right].into_iter().sum()
+    }
+}
+
+/// A reducer keeping the minimum of two optional partial minimums.
+///
+/// Keeps the left one if both are equal, like [`Iterator::min`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MinReducer;
+
+impl<T: Ord> Reducer<Option<T>> for MinReducer {
+    fn identity(&self) -> Option<T> {
+        None
+    }
+
+    fn reduce(&self, left: Option<T>, right: Option<T>) -> Option<T> {
+        match (left, right) {
+            (Some(left), Some(right)) => Some(if right < left { right } else { left }),
+            (left, right) => left.or(right),
+        }
+    }
+}
+
+/// A reducer keeping the maximum of two optional partial maximums.
+///
+/// Keeps the right one if both are equal, like [`Iterator::max`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MaxReducer;
+
+impl<T: Ord> Reducer<Option<T>> for MaxReducer {
+    fn identity(&self) -> Option<T> {
+        None
+    }
+
+    fn reduce(&self, left: Option<T>, right: Option<T>) -> Option<T> {
+        match (left, right) {
+            (Some(left), Some(right)) => Some(if right < left { left } else { right }),
+            (left, right) => left.or(right),
+        }
+    }
+}
+
+/// A reducer appending the right vector to the left one.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConcatReducer;
+
+impl<T> Reducer<Vec<T>> for ConcatReducer {
+    fn identity(&self) -> Vec<T> {
+        Vec::new()
+    }
+
+    fn reduce(&self, mut left: Vec<T>, mut right: Vec<T>) -> Vec<T> {
+        if left.is_empty() {
+            return right;
+        }
+        left.append(&mut right);
+        left
+    }
+}
+
+/// A reducer merging two hash maps, combining the values of keys present in both.
+///
+/// The smaller map is merged into the larger one, but `combine` always gets the value of the
+/// left map first.
+#[derive(Clone, Copy, Debug)]
+pub struct MergeReducer<F> {
+    /// The function combining the values of a key present in both maps.
+    combine: F,
+}
+
+impl<F> MergeReducer<F> {
+    /// Creates a reducer merging hash maps, combining the values of keys present in both with
+    /// `combine(left, right)`.
+    pub fn new(combine: F) -> Self {
+        MergeReducer { combine }
+    }
+}
+
+impl<K, V, S, F> Reducer<HashMap<K, V, S>> for MergeReducer<F>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+    F: Fn(V, V) -> V,
+{
+    fn identity(&self) -> HashMap<K, V, S> {
+        HashMap::default()
+    }
+
+    fn reduce(&self, mut left: HashMap<K, V, S>, mut right: HashMap<K, V, S>) -> HashMap<K, V, S> {
+        if left.len() >= right.len() {
+            for (k, r) in right {
+                let v = match left.remove(&k) {
+                    Some(l) => (self.combine)(l, r),
+                    None => r,
+                };
+                left.insert(k, v);
+            }
+            left
+        } else {
+            for (k, l) in left {
+                let v = match right.remove(&k) {
+                    Some(r) => (self.combine)(l, r),
+                    None => l,
+                };
+                right.insert(k, v);
+            }
+            right
+        }
+    }
+}
+
+/// A folder reducing the items themselves with a [`Reducer`], starting from its identity.
+pub struct ReduceFolder<B, R> {
+    /// The reduction of the items folded so far.
+    acc: B,
+    /// The reducer combining the items.
+    reducer: R,
+}
+
+impl<B, R: Reducer<B>> ReduceFolder<B, R> {
+    /// Creates a folder reducing the items with `reducer`.
+    pub fn new(reducer: R) -> Self {
+        ReduceFolder { acc: reducer.identity(), reducer }
+    }
+}
+
+impl<B, R> ReduceFolder<B, R> {
+    /// Creates a folder reducing the items with `reducer`, starting from `init`.
+    pub fn with_init(init: B, reducer: R) -> Self {
+        ReduceFolder { acc: init, reducer }
+    }
+
+    /// Returns the reducer combining the items.
+    pub fn reducer(&self) -> &R {
+        &self.reducer
+    }
+}
+
+impl<B, R> Folder<B> for ReduceFolder<B, R> where
+R: Reducer<B> {
+    type B = B;
+
+    fn fold<I: Iterator<Item=B>>(mut self, i: I) -> Self {
+        let reducer = &self.reducer;
+        self.acc = i.fold(self.acc, |acc, x| reducer.reduce(acc, x));
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_iter, AsyncIterator, Iter};
+    #[cfg(feature = "tokio")]
+    use crate::{IntoAsyncIterator, ParallelAsyncIterator};
+
+    #[tokio::test]
+    async fn test_async_reduce() {
+        let data: Vec<u64> = (1..=10_000).collect();
+        let copy = |x: &u64| *x;
+        let sum = Iter::new(&data).with_block_size(100).map(copy).async_reduce(|| 0, |a, b| a + b).await;
+        assert_eq!(sum, 50_005_000);
+
+        let longest = from_iter(["a", "abc", "ab"])
+            .async_reduce(|| "", |a, b| if b.len() > a.len() { b } else { a })
+            .await;
+        assert_eq!(longest, "abc");
+        assert_eq!(from_iter(0..0).reduce_with(SumReducer).await, 0);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_reducers_in_parallel_folds() {
+        let data: Vec<i64> = (0..1_000_000).map(|x| (x * 7_919) % 1_000_003 - 500_000).collect();
+
+        let min = data
+            .clone()
+            .into_async_iter()
+            .map(Some)
+            .par_fold_folder(ReduceFolder::new(MinReducer), MinReducer);
+        let keep = |best: Option<i64>, x| MaxReducer.reduce(best, Some(x));
+        let max = data.clone().into_async_iter().par_fold_with(keep, MaxReducer);
+        assert_eq!(min.await, data.iter().min().copied());
+        assert_eq!(max.await, data.iter().max().copied());
+
+        let sum = data.clone().into_async_iter().par_fold_with(|acc: i64, x| acc + x, SumReducer).await;
+        assert_eq!(sum, data.iter().sum::<i64>());
+
+        let push = |mut v: Vec<i64>, x| { v.push(x); v };
+        let all = data.clone().into_async_iter().par_fold_with(push, ConcatReducer).await;
+        assert_eq!(all, data);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_merge_reducer_counts_words() {
+        let text: Vec<&'static str> = "the quick fox jumps over the lazy dog the end"
+            .split(' ')
+            .cycle()
+            .take(100_000)
+            .collect();
+        let count = |mut counts: HashMap<&'static str, usize>, word| {
+            *counts.entry(word).or_default() += 1;
+            counts
+        };
+        let counts = text
+            .into_async_iter()
+            .par_fold_with(count, MergeReducer::new(|a, b| a + b))
+            .await;
+        assert_eq!(counts.len(), 8);
+        assert_eq!(counts["the"], 30_000);
+        assert_eq!(counts.values().sum::<usize>(), 100_000);
+    }
+}
@@ -0,0 +1,275 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A future giving the worker back to the executor once, then completing.
+///
+/// Created with [`yield_now`].
+#[derive(Debug, Default)]
+#[must_use = "futures do nothing unless awaited"]
+pub struct YieldNow {
+    /// Whether the future already returned `Pending` once.
+    yielded: bool,
+}
+
+/// Yields once to the executor, whatever it is.
+///
+/// The task wakes itself up and returns `Pending`, so the executor polls its other tasks
+/// before polling this one again. This works on any executor, unlike the runtime-specific
+/// `yield_now` functions.
+pub fn yield_now() -> YieldNow {
+    YieldNow::default()
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Yields once to the runtime running the task.
+///
+/// Inside a tokio runtime, uses `tokio::task::yield_now`, which lets the scheduler put the
+/// task behind the others. Falls back to the portable [`yield_now`] anywhere else.
+pub(crate) async fn yield_to_runtime() {
+    #[cfg(feature = "tokio")]
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return tokio::task::yield_now().await;
+    }
+    yield_now().await
+}
+
+/// Spawns `future` on the runtime running the current task, returning a future of its output.
+///
+/// Uses the tokio runtime if there is one, then async-std, then smol, depending on the
+/// enabled features. A panic in the spawned task is resumed when awaiting its output.
+///
+/// # Panics
+/// Panics if the only enabled runtime is tokio and there is no tokio runtime to spawn on.
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+pub(crate) fn spawn<F>(future: F) -> Pin<Box<dyn Future<Output = F::Output> + Send>>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    #[cfg(feature = "tokio")]
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        let task = handle.spawn(future);
+        return Box::pin(async move {
+            match task.await {
+                Ok(output) => output,
+                Err(e) => std::panic::resume_unwind(e.into_panic()),
+            }
+        });
+    }
+    spawn_portable(future)
+}
+
+/// Spawns `future` on async-std's global executor.
+#[cfg(feature = "async-std")]
+fn spawn_portable<F>(future: F) -> Pin<Box<dyn Future<Output = F::Output> + Send>>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    Box::pin(async_std::task::spawn(future))
+}
+
+/// Spawns `future` on smol's global executor.
+#[cfg(all(feature = "smol", not(feature = "async-std")))]
+fn spawn_portable<F>(future: F) -> Pin<Box<dyn Future<Output = F::Output> + Send>>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    Box::pin(smol::spawn(future))
+}
+
+/// Fails to spawn `future`, since there is no tokio runtime and no other runtime enabled.
+#[cfg(all(feature = "tokio", not(any(feature = "async-std", feature = "smol"))))]
+fn spawn_portable<F>(_future: F) -> Pin<Box<dyn Future<Output = F::Output> + Send>>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    panic!("parallel folds must run inside a tokio runtime")
+}
+
+/// Runs the blocking function `f` on a thread where blocking is allowed, returning a future of
+/// its output.
+///
+/// Uses the blocking pool of the tokio runtime if there is one, then async-std's, then smol's,
+/// depending on the enabled features. Use it to run a synchronous fold, such as the scoped
+/// parallel folds, without stalling the executor. A panic in `f` is resumed when awaiting its
+/// output.
+///
+/// # Panics
+/// Panics if the only enabled runtime is tokio and there is no tokio runtime to run `f` on.
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+pub fn spawn_blocking<F, R>(f: F) -> Pin<Box<dyn Future<Output = R> + Send>>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    #[cfg(feature = "tokio")]
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        let task = handle.spawn_blocking(f);
+        return Box::pin(async move {
+            match task.await {
+                Ok(output) => output,
+                Err(e) => std::panic::resume_unwind(e.into_panic()),
+            }
+        });
+    }
+    spawn_blocking_portable(f)
+}
+
+/// Runs `f` on async-std's blocking thread pool.
+#[cfg(feature = "async-std")]
+fn spawn_blocking_portable<F, R>(f: F) -> Pin<Box<dyn Future<Output = R> + Send>>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    Box::pin(async_std::task::spawn_blocking(f))
+}
+
+/// Runs `f` on smol's blocking thread pool.
+#[cfg(all(feature = "smol", not(feature = "async-std")))]
+fn spawn_blocking_portable<F, R>(f: F) -> Pin<Box<dyn Future<Output = R> + Send>>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    Box::pin(smol::unblock(f))
+}
+
+/// Fails to run `f`, since there is no tokio runtime and no other runtime enabled.
+#[cfg(all(feature = "tokio", not(any(feature = "async-std", feature = "smol"))))]
+fn spawn_blocking_portable<F, R>(_f: F) -> Pin<Box<dyn Future<Output = R> + Send>>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    panic!("blocking functions must run inside a tokio runtime")
+}
+
+/// A waker unparking the thread blocked on a future.
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+struct ThreadWaker(std::thread::Thread);
+
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+impl std::task::Wake for ThreadWaker {
+    fn wake(self: std::sync::Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &std::sync::Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls `future` to completion on the current thread, parking it while the future is pending.
+///
+/// Runs outside any runtime, so the folds it drives fall back to the portable [`yield_now`].
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let waker = std::sync::Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AsyncIterator, Iter};
+    use std::task::Waker;
+
+    /// Polls `future` to completion on the current thread without any runtime, returning its
+    /// output and how many times it returned `Pending`.
+    fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+        let mut future = std::pin::pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut pending = 0;
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return (output, pending),
+                Poll::Pending => pending += 1,
+            }
+        }
+    }
+
+    #[test]
+    fn test_fold_without_runtime() {
+        let data: Vec<u32> = (0..10_000).collect();
+        let fold = Iter::new(&data).with_block_size(1_000).async_fold(0, |acc, &x| acc + x);
+        let (sum, pending) = block_on(fold);
+        assert_eq!(sum, 49_995_000);
+        // The default strategy yielded between every two blocks.
+        assert_eq!(pending, 9);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_spawn_blocking_on_tokio() {
+        use crate::ScopedParallelAsyncIterator;
+
+        let data: Vec<u64> = (0..1_000_000).collect();
+        let sum = spawn_blocking(move || {
+            Iter::new(&data).par_fold_blocking(|| 0, |acc, &x| acc + x, |a, b| a + b)
+        });
+        assert_eq!(sum.await, 499_999_500_000);
+
+        let panicked = tokio::spawn(spawn_blocking(|| panic!("blocking failure"))).await;
+        assert!(panicked.unwrap_err().is_panic());
+    }
+
+    #[cfg(feature = "smol")]
+    #[test]
+    fn test_spawn_blocking_on_smol() {
+        let sum = smol::block_on(spawn_blocking(|| (0..1_000u64).sum::<u64>()));
+        assert_eq!(sum, 499_500);
+    }
+
+    #[cfg(feature = "async-std")]
+    #[test]
+    fn test_spawn_blocking_on_async_std() {
+        let sum = async_std::task::block_on(spawn_blocking(|| (0..1_000u64).sum::<u64>()));
+        assert_eq!(sum, 499_500);
+    }
+
+    #[cfg(feature = "smol")]
+    #[test]
+    fn test_par_fold_on_smol() {
+        use crate::{IntoAsyncIterator, ParallelAsyncIterator};
+
+        let data: Vec<u64> = (0..1_000_000).collect();
+        let fold = data.into_async_iter().par_fold(|| 0, |acc, x| acc + x, |a, b| a + b);
+        let sum = smol::block_on(fold);
+        assert_eq!(sum, 499_999_500_000);
+    }
+
+    #[cfg(feature = "async-std")]
+    #[test]
+    fn test_par_fold_on_async_std() {
+        use crate::{IntoAsyncIterator, ParallelAsyncIterator};
+
+        let data: Vec<u64> = (0..1_000_000).collect();
+        let fold = data.into_async_iter().par_fold(|| 0, |acc, x| acc + x, |a, b| a + b);
+        let sum = async_std::task::block_on(fold);
+        assert_eq!(sum, 499_999_500_000);
+    }
+}
@@ -0,0 +1,261 @@
+use crate::Folder;
+
+/// A folder checking whether any item satisfies a predicate.
+///
+/// Gets [full](Folder::full) at the first match.
+pub struct AnyFolder<P> {
+    /// The predicate.
+    pred: P,
+    /// Whether an item satisfied the predicate.
+    found: bool,
+}
+
+impl<P> AnyFolder<P> {
+    /// Creates a folder checking whether any item satisfies `pred`.
+    pub fn new(pred: P) -> Self {
+        AnyFolder { pred, found: false }
+    }
+}
+
+impl<Item, P> Folder<Item> for AnyFolder<P> where
+P: FnMut(Item) -> bool {
+    type B = bool;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, mut i: I) -> Self {
+        self.found = self.found || i.any(&mut self.pred);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.found
+    }
+
+    fn full(&self) -> bool {
+        self.found
+    }
+}
+
+/// A folder checking whether every item satisfies a predicate.
+///
+/// Gets [full](Folder::full) at the first item that doesn't.
+pub struct AllFolder<P> {
+    /// The predicate.
+    pred: P,
+    /// Whether every item folded so far satisfied the predicate.
+    all: bool,
+}
+
+impl<P> AllFolder<P> {
+    /// Creates a folder checking whether every item satisfies `pred`.
+    pub fn new(pred: P) -> Self {
+        AllFolder { pred, all: true }
+    }
+}
+
+impl<Item, P> Folder<Item> for AllFolder<P> where
+P: FnMut(Item) -> bool {
+    type B = bool;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, mut i: I) -> Self {
+        self.all = self.all && i.all(&mut self.pred);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.all
+    }
+
+    fn full(&self) -> bool {
+        !self.all
+    }
+}
+
+/// A folder searching for the first item satisfying a predicate.
+///
+/// Gets [full](Folder::full) at the first match.
+pub struct FindFolder<P, T> {
+    /// The predicate.
+    pred: P,
+    /// The first item that satisfied the predicate.
+    found: Option<T>,
+}
+
+impl<P, T> FindFolder<P, T> {
+    /// Creates a folder searching for the first item satisfying `pred`.
+    pub fn new(pred: P) -> Self {
+        FindFolder { pred, found: None }
+    }
+}
+
+impl<Item, P> Folder<Item> for FindFolder<P, Item> where
+P: FnMut(&Item) -> bool {
+    type B = Option<Item>;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, mut i: I) -> Self {
+        if self.found.is_none() {
+            self.found = i.find(&mut self.pred);
+        }
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.found
+    }
+
+    fn full(&self) -> bool {
+        self.found.is_some()
+    }
+}
+
+/// A folder returning the first non-`None` result of an operation.
+///
+/// Gets [full](Folder::full) at the first `Some`.
+pub struct FindMapFolder<O, R> {
+    /// The operation.
+    op: O,
+    /// The first non-`None` result of the operation.
+    found: Option<R>,
+}
+
+impl<O, R> FindMapFolder<O, R> {
+    /// Creates a folder returning the first non-`None` result of `op`.
+    pub fn new(op: O) -> Self {
+        FindMapFolder { op, found: None }
+    }
+}
+
+impl<Item, R, O> Folder<Item> for FindMapFolder<O, R> where
+O: FnMut(Item) -> Option<R> {
+    type B = Option<R>;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, mut i: I) -> Self {
+        if self.found.is_none() {
+            self.found = i.find_map(&mut self.op);
+        }
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.found
+    }
+
+    fn full(&self) -> bool {
+        self.found.is_some()
+    }
+}
+
+/// A folder searching for the index of the first item satisfying a predicate.
+///
+/// The index counts items across blocks. Gets [full](Folder::full) at the first match.
+pub struct PositionFolder<P> {
+    /// The predicate.
+    pred: P,
+    /// The number of items folded so far without a match.
+    index: usize,
+    /// The index of the first item that satisfied the predicate.
+    found: Option<usize>,
+}
+
+impl<P> PositionFolder<P> {
+    /// Creates a folder searching for the index of the first item satisfying `pred`.
+    pub fn new(pred: P) -> Self {
+        PositionFolder { pred, index: 0, found: None }
+    }
+}
+
+impl<Item, P> Folder<Item> for PositionFolder<P> where
+P: FnMut(Item) -> bool {
+    type B = Option<usize>;
+
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        if self.found.is_some() {
+            return self;
+        }
+        for item in i {
+            if (self.pred)(item) {
+                self.found = Some(self.index);
+                break;
+            }
+            self.index += 1;
+        }
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.found
+    }
+
+    fn full(&self) -> bool {
+        self.found.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsyncIterator, Iter};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Returns a predicate matching `target` that counts the items it is called on in `seen`.
+    fn counting_eq(target: u32, seen: &AtomicUsize) -> impl FnMut(&u32) -> bool + Send + '_ {
+        move |&x| {
+            seen.fetch_add(1, Ordering::Relaxed);
+            x == target
+        }
+    }
+
+    #[tokio::test]
+    async fn test_any_stops_at_first_match() {
+        let data: Vec<u32> = (0..10_000_000).collect();
+        let seen = AtomicUsize::new(0);
+        let found = Iter::new(&data).with_block_size(1_000).any(counting_eq(1_500, &seen)).await;
+        assert!(found);
+        assert_eq!(seen.load(Ordering::Relaxed), 1_501);
+
+        assert!(!Iter::new(&data[..100]).any(|&x| x == 1_500).await);
+    }
+
+    #[tokio::test]
+    async fn test_all() {
+        let data: Vec<u32> = (0..10_000).collect();
+        assert!(Iter::new(&data).with_block_size(100).all(|&x| x < 10_000).await);
+
+        let seen = AtomicUsize::new(0);
+        let all = Iter::new(&data)
+            .with_block_size(100)
+            .all(|&x| {
+                seen.fetch_add(1, Ordering::Relaxed);
+                x < 250
+            })
+            .await;
+        assert!(!all);
+        assert_eq!(seen.load(Ordering::Relaxed), 251);
+    }
+
+    #[tokio::test]
+    async fn test_find() {
+        let data: Vec<u32> = (0..10_000).collect();
+        let found = Iter::new(&data).with_block_size(64).find(|&&x| x > 0 && x % 700 == 0).await;
+        assert_eq!(found, Some(&700));
+        assert_eq!(Iter::new(&data).find(|&&x| x > 10_000).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_find_map_through_filter() {
+        let data: Vec<u32> = (0..10_000).collect();
+        let odd = |x: &&u32| *x % 2 == 1;
+        let found = Iter::new(&data)
+            .with_block_size(10)
+            .filter(odd)
+            .find_map(|&x| (x > 50).then(|| x * 2))
+            .await;
+        assert_eq!(found, Some(102));
+    }
+
+    #[tokio::test]
+    async fn test_position_counts_across_blocks() {
+        let data: Vec<u32> = (0..10_000).rev().collect();
+        let position = Iter::new(&data).with_block_size(7).position(|&x| x == 5_000).await;
+        assert_eq!(position, Some(4_999));
+        assert_eq!(Iter::new(&data).position(|&x| x > 10_000).await, None);
+    }
+}
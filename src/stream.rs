@@ -0,0 +1,348 @@
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use futures::{Stream, StreamExt};
+
+use crate::driver::{drive, Blocks, Schedule};
+use crate::progress::ProgressHook;
+use crate::{AlwaysYield, AsyncIterator, BlockSize, Folder, Progress, YieldStrategy};
+
+/// A [`Stream`] pulling the items of an [`AsyncIterator`] one at a time.
+///
+/// The fold runs inside `poll_next`, on the consumer's task. It hands every block over to the
+/// stream and waits for the stream to drain it before folding the next one, so at most one
+/// block of items is buffered. Created by [`AsyncIterator::into_stream`].
+pub struct IntoStream<'a, T> {
+    /// The fold pushing blocks into `block`, until it completes.
+    fold: Option<Pin<Box<dyn Future<Output = ()> + Send + 'a>>>,
+    /// The last block folded, waiting to be taken by the stream.
+    block: Arc<Mutex<Vec<T>>>,
+    /// The items of the block being pulled.
+    ready: std::vec::IntoIter<T>,
+}
+
+impl<'a, T: Send + 'a> IntoStream<'a, T> {
+    /// Creates a stream pulling the items of `iter`.
+    pub(crate) fn new<I>(iter: I) -> Self
+    where
+        I: AsyncIterator<Item = T> + Send + 'a,
+    {
+        let block = Arc::new(Mutex::new(Vec::new()));
+        let fold = iter.fold_folder(StreamFolder { block: block.clone() });
+        IntoStream { fold: Some(Box::pin(fold)), block, ready: Vec::new().into_iter() }
+    }
+}
+
+// The items are never pinned, and the fold is pinned on the heap.
+impl<T> Unpin for IntoStream<'_, T> {}
+
+impl<T> Stream for IntoStream<'_, T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.ready.next() {
+                return Poll::Ready(Some(item));
+            }
+            let block = std::mem::take(&mut *this.block.lock().unwrap());
+            if !block.is_empty() {
+                this.ready = block.into_iter();
+                continue;
+            }
+            let Some(fold) = this.fold.as_mut() else {
+                return Poll::Ready(None);
+            };
+            match fold.as_mut().poll(cx) {
+                Poll::Ready(()) => this.fold = None,
+                // The fold is waiting for the stream to take the block it just folded.
+                Poll::Pending if !this.block.lock().unwrap().is_empty() => {}
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.ready.len();
+        (len, self.fold.is_none().then_some(len))
+    }
+}
+
+/// A folder handing every block over to an [`IntoStream`].
+struct StreamFolder<T> {
+    /// The block shared with the stream.
+    block: Arc<Mutex<Vec<T>>>,
+}
+
+impl<T: Send> Folder<T> for StreamFolder<T> {
+    type B = ();
+
+    fn fold<I: Iterator<Item=T>>(self, i: I) -> Self {
+        self.block.lock().unwrap().extend(i);
+        self
+    }
+
+    fn into_result(self) -> Self::B {}
+
+    /// Waits for the stream to take the block.
+    ///
+    /// The stream only polls the fold once it took the block, so there is no waker to
+    /// register: a pending fold makes the stream look at the block again.
+    fn settle(&mut self) -> impl Future<Output = ()> + Send {
+        poll_fn(|_| {
+            if self.block.lock().unwrap().is_empty() { Poll::Ready(()) } else { Poll::Pending }
+        })
+    }
+}
+
+/// An asynchronous iterator over the items of a [`Stream`].
+///
+/// Items are pulled from the stream until a block is complete, then folded at once. Created
+/// with [`from_stream`].
+pub struct FromStream<S, Y = AlwaysYield> {
+    /// The stream of items left to fold.
+    stream: S,
+    /// The block size policy and yield strategy pacing the fold.
+    schedule: Schedule<Y>,
+}
+
+/// Creates an asynchronous iterator over the items of `stream`.
+///
+/// Uses the [`DEFAULT_BLOCK_SIZE`](crate::DEFAULT_BLOCK_SIZE) and yields after every block.
+pub fn from_stream<S: Stream>(stream: S) -> FromStream<S> {
+    FromStream::new(stream)
+}
+
+impl<S: Stream> FromStream<S> {
+    /// Creates an asynchronous iterator over the items of `stream`.
+    pub fn new(stream: S) -> Self {
+        FromStream { stream, schedule: Schedule::default() }
+    }
+}
+
+impl<S: Stream, Y> FromStream<S, Y> {
+    /// Sets the policy deciding how many elements are folded between two yield points.
+    ///
+    /// # Panics
+    /// Panics if a fixed `block_size` is zero.
+    pub fn with_block_size(mut self, block_size: impl Into<BlockSize>) -> Self {
+        self.schedule = self.schedule.with_block_size(block_size);
+        self
+    }
+
+    /// Returns the policy deciding how many elements are folded between two yield points.
+    pub fn block_size(&self) -> &BlockSize {
+        &self.schedule.block_size
+    }
+
+    /// Sets the strategy deciding whether to yield back to the runtime between two blocks.
+    pub fn with_yield_strategy<Y2: YieldStrategy>(self, yield_strategy: Y2) -> FromStream<S, Y2> {
+        FromStream { stream: self.stream, schedule: self.schedule.with_yield_strategy(yield_strategy) }
+    }
+
+    /// Sets the callback receiving the [`Progress`] of the fold at every block boundary.
+    ///
+    /// The estimated time left is only known if the stream's size hint is exact.
+    pub fn with_progress(mut self, callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        self.schedule = self.schedule.with_progress(ProgressHook::new(callback));
+        self
+    }
+}
+
+/// An asynchronous iterator that folds the items of a stream.
+///
+/// # Type Parameters
+/// - `S`: The wrapped stream type.
+/// - `Y`: The strategy deciding whether to yield between two blocks.
+impl<S, Y> AsyncIterator for FromStream<S, Y>
+where
+    S: Stream + Send,
+    S::Item: Send,
+    Y: YieldStrategy,
+{
+    /// The type of items yielded by the iterator.
+    type Item = S::Item;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for asynchronous folding.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        let blocks = StreamBlocks { stream: Box::pin(self.stream), block: Vec::new(), ended: false };
+        drive(blocks, self.schedule, folder)
+    }
+}
+
+/// The items of a stream, pulled a block at a time so the driver can fold them.
+struct StreamBlocks<S: Stream> {
+    /// The stream of items left to pull.
+    stream: Pin<Box<S>>,
+    /// The items pulled for the next block.
+    block: Vec<S::Item>,
+    /// Whether the stream ended.
+    ended: bool,
+}
+
+impl<S> Blocks for StreamBlocks<S>
+where
+    S: Stream + Send,
+    S::Item: Send,
+{
+    type Item = S::Item;
+
+    /// Pulls items from the stream until the block holds `n` of them or the stream ends.
+    ///
+    /// One more item is read ahead, so a stream ending with the block is known to be exhausted
+    /// once the block is folded, instead of being folded again as an empty block.
+    async fn fetch(&mut self, n: usize) {
+        while self.block.len() <= n && !self.ended {
+            match self.stream.next().await {
+                Some(item) => self.block.push(item),
+                None => self.ended = true,
+            }
+        }
+    }
+
+    fn fold_block<F: Folder<Self::Item>>(&mut self, folder: F, n: usize) -> (F, usize) {
+        let len = self.block.len().min(n);
+        (folder.fold(self.block.drain(..len)), len)
+    }
+
+    fn is_exhausted(&mut self) -> bool {
+        self.ended && self.block.is_empty()
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        match self.stream.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower + self.block.len()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_iter, Iter};
+    use futures::stream;
+    use crate::UntilDeadline;
+    use std::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn test_into_stream_pulls_items_in_order() {
+        let data: Vec<u32> = (0..10_000).collect();
+        let odd = |x: &&u32| *x % 2 == 1;
+        let items: Vec<&u32> =
+            Iter::new(&data).with_block_size(100).filter(odd).into_stream().collect().await;
+        assert_eq!(items.len(), 5_000);
+        assert_eq!(items[..3], [&1, &3, &5]);
+
+        let mut stream = from_iter(0..3).into_stream();
+        assert_eq!(stream.next().await, Some(0));
+        assert_eq!(stream.next().await, Some(1));
+        assert_eq!(stream.next().await, Some(2));
+        assert_eq!(stream.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_into_stream_buffers_one_block() {
+        let folded = Arc::new(Mutex::new(0));
+        let count = {
+            let folded = folded.clone();
+            move |x: u32| {
+                *folded.lock().unwrap() += 1;
+                x
+            }
+        };
+        let mut stream = from_iter(0..1_000u32).with_block_size(64).map(count).into_stream();
+        assert_eq!(stream.next().await, Some(0));
+        assert_eq!(*folded.lock().unwrap(), 64);
+        let rest: Vec<u32> = stream.take(100).collect().await;
+        assert_eq!(rest.last(), Some(&100));
+        assert_eq!(*folded.lock().unwrap(), 128);
+    }
+
+    #[tokio::test]
+    async fn test_from_stream_drives_any_folder() {
+        let source = stream::iter(0..10_000u64).filter(|x| std::future::ready(x % 3 == 0));
+        let sum = from_stream(source).with_block_size(128).async_fold(0, |acc, x| acc + x).await;
+        assert_eq!(sum, (0..10_000u64).filter(|x| x % 3 == 0).sum::<u64>());
+
+        let found = from_stream(stream::iter(["a", "bb", "ccc"])).find(|s| s.len() == 2).await;
+        assert_eq!(found, Some("bb"));
+        assert_eq!(from_stream(stream::empty::<u8>()).count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_from_stream_reports_progress() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let record = {
+            let reports = reports.clone();
+            move |progress| reports.lock().unwrap().push(progress)
+        };
+        let sum = from_stream(stream::iter(0..1_000u64))
+            .with_block_size(300)
+            .with_progress(record)
+            .async_fold(0, |acc, x| acc + x)
+            .await;
+        assert_eq!(sum, 499_500);
+
+        let reports = std::mem::take(&mut *reports.lock().unwrap());
+        let elements: Vec<usize> = reports.iter().map(|p| p.elements).collect();
+        assert_eq!(elements, [300, 600, 900, 1_000]);
+        assert_eq!(reports.last().map(|p| p.blocks), Some(4));
+        // The stream's size hint is exact, so the time left is estimated.
+        assert!(reports.iter().all(|p| p.remaining.is_some()));
+
+        let remaining = Arc::new(Mutex::new(Vec::new()));
+        let record = {
+            let remaining = remaining.clone();
+            move |progress: Progress| remaining.lock().unwrap().push(progress.remaining)
+        };
+        let source = stream::iter(0..1_000u64).filter(|x| std::future::ready(x % 3 == 0));
+        from_stream(source).with_block_size(100).with_progress(record).count().await;
+        // A filtered stream doesn't know its length until it ends.
+        let remaining = remaining.lock().unwrap();
+        assert!(remaining[..remaining.len() - 1].iter().all(Option::is_none));
+        assert_eq!(remaining.last(), Some(&Some(Duration::ZERO)));
+    }
+
+    #[tokio::test]
+    async fn test_from_stream_ends_with_an_exact_block() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let record = {
+            let reports = reports.clone();
+            move |p: Progress| reports.lock().unwrap().push((p.elements, p.blocks))
+        };
+        let count = from_stream(stream::iter(0..1_000u64))
+            .with_block_size(250)
+            .with_progress(record)
+            .count()
+            .await;
+        assert_eq!(count, 1_000);
+        assert_eq!(reports.lock().unwrap().last(), Some(&(1_000, 4)));
+
+        // The deadline passes while the last block is pulled, but every item is folded.
+        let slow = |x: u64| async move {
+            if x == 150 {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+            x
+        };
+        let deadline = Instant::now() + Duration::from_millis(20);
+        let result = from_stream(stream::iter(0..200u64).then(slow))
+            .with_block_size(100)
+            .fold_until(0, |acc, x| acc + x, deadline)
+            .await;
+        assert_eq!(result, UntilDeadline::Complete(19_900));
+    }
+}
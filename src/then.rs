@@ -0,0 +1,320 @@
+use std::future::Future;
+use std::iter::once;
+
+use crate::{AsyncIterator, Folder};
+
+/// The panic message for a folder used while it is settling, which the drivers never do.
+const SETTLING: &str = "folder used while settling";
+
+/// An iterator mapping each item to a future and yielding its output.
+pub struct Then<I, O> {
+    /// The base iterator.
+    base: I,
+    /// The asynchronous mapping operation.
+    op: O,
+}
+
+impl<I, O> Then<I, O> {
+    /// Creates an iterator mapping the items of `base` with `op`.
+    pub(crate) fn new(base: I, op: O) -> Self {
+        Then { base, op }
+    }
+}
+
+/// An asynchronous iterator that maps items using an asynchronous operation.
+///
+/// # Type Parameters
+/// - `R`: The type of items produced by the futures.
+/// - `I`: The inner asynchronous iterator type that this `Then` iterator adapts.
+/// - `O`: The type of the asynchronous mapping operation.
+/// - `Fut`: The type of the futures returned by the operation.
+impl<R, I, O, Fut> AsyncIterator for Then<I, O>
+where
+    I: AsyncIterator + Send,
+    I::Item: Send,
+    O: FnMut(I::Item) -> Fut + Send,
+    Fut: Future<Output = R> + Send,
+{
+    /// The type of items yielded by the iterator.
+    type Item = R;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for folding the items.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        let then_folder = ThenFolder::new(folder, self.op);
+        self.base.fold_folder(then_folder)
+    }
+}
+
+/// A folder for the then iterator.
+///
+/// Buffers the items of a block, then awaits their futures one at a time when the block is
+/// [settled](Folder::settle), passing every output to the inner folder as soon as it is ready.
+pub struct ThenFolder<F, O, T> {
+    /// The inner folder, only missing while settling.
+    folder: Option<F>,
+    /// The asynchronous mapping operation.
+    op: O,
+    /// The items of the block being folded.
+    items: Vec<T>,
+}
+
+impl<F, O, T> ThenFolder<F, O, T> {
+    /// Creates a folder passing the outputs of `op` to `folder`.
+    pub fn new(folder: F, op: O) -> Self {
+        ThenFolder { folder: Some(folder), op, items: Vec::new() }
+    }
+}
+
+impl<Item, R, F, O, Fut> Folder<Item> for ThenFolder<F, O, Item> where
+F: Folder<R> + Send,
+O: FnMut(Item) -> Fut + Send,
+Fut: Future<Output = R> + Send,
+Item: Send {
+    /// The type of result produced by the adapted folder.
+    type B = F::B;
+
+    /// Buffers the items until the block is settled.
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        self.items.extend(i);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.expect(SETTLING).into_result()
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn full(&self) -> bool {
+        self.folder.as_ref().is_some_and(|folder| folder.full())
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.as_ref().is_some_and(|folder| folder.cancelled())
+    }
+
+    /// Awaits the futures of the buffered items in order, stopping once the inner folder is
+    /// full.
+    async fn settle(&mut self) {
+        let mut folder = self.folder.take().expect(SETTLING);
+        for item in self.items.drain(..) {
+            if folder.full() {
+                break;
+            }
+            folder = folder.fold(once((self.op)(item).await));
+            folder.settle().await;
+        }
+        self.folder = Some(folder);
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.as_mut().expect(SETTLING).flush()
+    }
+}
+
+/// An iterator selecting items based on an asynchronous predicate.
+pub struct FilterAsync<I, P> {
+    /// The base iterator.
+    base: I,
+    /// The asynchronous filtering predicate.
+    pred: P,
+}
+
+impl<I, P> FilterAsync<I, P> {
+    /// Creates an iterator selecting the items of `base` with `pred`.
+    pub(crate) fn new(base: I, pred: P) -> Self {
+        FilterAsync { base, pred }
+    }
+}
+
+/// An asynchronous iterator that filters items based on an asynchronous predicate.
+///
+/// # Type Parameters
+/// - `I`: The inner asynchronous iterator type that this `FilterAsync` iterator adapts.
+/// - `P`: The type of the asynchronous filtering predicate.
+/// - `Fut`: The type of the futures returned by the predicate.
+impl<I, P, Fut> AsyncIterator for FilterAsync<I, P>
+where
+    I: AsyncIterator + Send,
+    I::Item: Send,
+    P: FnMut(&I::Item) -> Fut + Send,
+    Fut: Future<Output = bool> + Send,
+{
+    /// The type of items yielded by the iterator.
+    type Item = I::Item;
+
+    /// Asynchronously folds the iterator using the provided folder.
+    ///
+    /// # Parameters
+    /// - `folder`: The folder used for folding the items.
+    ///
+    /// # Returns
+    /// The result of folding, represented by the type `F::B`.
+    fn fold_folder<F>(self, folder: F) -> impl Future<Output = F::B> + Send
+    where
+    Self: Send,
+    F: Folder<Self::Item> + Send
+    {
+        let filter_folder = FilterAsyncFolder::new(folder, self.pred);
+        self.base.fold_folder(filter_folder)
+    }
+}
+
+/// A folder for the asynchronous filter iterator.
+///
+/// Buffers the items of a block, then awaits the predicate on each of them when the block is
+/// [settled](Folder::settle), passing every selected item to the inner folder.
+pub struct FilterAsyncFolder<F, P, T> {
+    /// The inner folder, only missing while settling.
+    folder: Option<F>,
+    /// The asynchronous filtering predicate.
+    pred: P,
+    /// The items of the block being folded.
+    items: Vec<T>,
+}
+
+impl<F, P, T> FilterAsyncFolder<F, P, T> {
+    /// Creates a folder passing the items selected by `pred` to `folder`.
+    pub fn new(folder: F, pred: P) -> Self {
+        FilterAsyncFolder { folder: Some(folder), pred, items: Vec::new() }
+    }
+}
+
+impl<Item, F, P, Fut> Folder<Item> for FilterAsyncFolder<F, P, Item> where
+F: Folder<Item> + Send,
+P: FnMut(&Item) -> Fut + Send,
+Fut: Future<Output = bool> + Send,
+Item: Send {
+    /// The type of result produced by the adapted folder.
+    type B = F::B;
+
+    /// Buffers the items until the block is settled.
+    fn fold<I: Iterator<Item=Item>>(mut self, i: I) -> Self {
+        self.items.extend(i);
+        self
+    }
+
+    fn into_result(self) -> Self::B {
+        self.folder.expect(SETTLING).into_result()
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn full(&self) -> bool {
+        self.folder.as_ref().is_some_and(|folder| folder.full())
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn cancelled(&self) -> bool {
+        self.folder.as_ref().is_some_and(|folder| folder.cancelled())
+    }
+
+    /// Awaits the predicate on the buffered items in order, stopping once the inner folder is
+    /// full.
+    async fn settle(&mut self) {
+        let mut folder = self.folder.take().expect(SETTLING);
+        for item in self.items.drain(..) {
+            if folder.full() {
+                break;
+            }
+            if (self.pred)(&item).await {
+                folder = folder.fold(once(item));
+                folder.settle().await;
+            }
+        }
+        self.folder = Some(folder);
+    }
+
+    /// Forwards to the adapted inner folder.
+    fn flush(&mut self) -> impl Future<Output = ()> + Send {
+        self.folder.as_mut().expect(SETTLING).flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_iter, AsyncIterator, Iter};
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::RwLock;
+
+    #[tokio::test]
+    async fn test_then_looks_up_a_shared_cache() {
+        let cache: Arc<RwLock<HashMap<u32, String>>> =
+            Arc::new(RwLock::new((0..100).map(|k| (k, format!("v{k}"))).collect()));
+        let lookup = |k: u32| {
+            let cache = cache.clone();
+            async move { cache.read().await.get(&(k % 100)).cloned() }
+        };
+        let values: Vec<Option<String>> =
+            from_iter(0..1_000u32).with_block_size(64).then(lookup).collect().await;
+        assert_eq!(values.len(), 1_000);
+        assert_eq!(values[345].as_deref(), Some("v45"));
+
+        let double = |x: &u32| { let x = *x; async move { x * 2 } };
+        let data: Vec<u32> = (0..1_000).collect();
+        let sum = Iter::new(&data).with_block_size(10).map_async(double).sum::<u32>().await;
+        assert_eq!(sum, 999_000);
+    }
+
+    #[tokio::test]
+    async fn test_filter_async_stops_with_the_folder() {
+        let data: Vec<u32> = (0..100_000).collect();
+        let seen = AtomicUsize::new(0);
+        let is_multiple_of_7 = |x: &&u32| {
+            seen.fetch_add(1, Ordering::Relaxed);
+            let x = **x;
+            async move { x > 0 && x.is_multiple_of(7) }
+        };
+        let found = Iter::new(&data)
+            .with_block_size(100)
+            .filter_async(is_multiple_of_7)
+            .find(|_| true)
+            .await;
+        assert_eq!(found, Some(&7));
+        // The rest of the block was neither awaited nor folded.
+        assert_eq!(seen.load(Ordering::Relaxed), 8);
+
+        let odd = |x: &u32| { let x = *x; async move { x % 2 == 1 } };
+        assert_eq!(from_iter(0..10u32).filter_async(odd).collect_vec().await, [1, 3, 5, 7, 9]);
+    }
+
+    #[tokio::test]
+    async fn test_then_lets_other_tasks_run() {
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let ticker = {
+            let ticks = ticks.clone();
+            tokio::spawn(async move {
+                loop {
+                    ticks.fetch_add(1, Ordering::Relaxed);
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                }
+            })
+        };
+        let slow = |x: u32| async move {
+            if x == 3 {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            x
+        };
+        let result = from_iter(0..5u32).then(slow).collect_vec().await;
+        ticker.abort();
+
+        assert_eq!(result, [0, 1, 2, 3, 4]);
+        // The ticker shares the current-thread runtime, so it only ran while the slow future
+        // was pending.
+        assert!(ticks.load(Ordering::Relaxed) > 1);
+    }
+}
@@ -0,0 +1,213 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use crate::runtime::yield_to_runtime;
+
+/// A strategy deciding whether the folding driver gives the worker back to the runtime.
+///
+/// The driver calls [`YieldStrategy::start`] once before folding the first block and
+/// [`YieldStrategy::after_block`] between two blocks.
+pub trait YieldStrategy: Send {
+    /// Prepares the strategy for a new fold.
+    fn start(&mut self) {}
+
+    /// Called by the driver after a block was folded, when more blocks remain.
+    fn after_block(&mut self) -> impl Future<Output = ()> + Send;
+}
+
+impl<Y: YieldStrategy> YieldStrategy for Box<Y> {
+    fn start(&mut self) {
+        (**self).start()
+    }
+
+    async fn after_block(&mut self) {
+        (**self).after_block().await
+    }
+}
+
+/// A strategy that never yields, folding the whole input in one go.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NeverYield;
+
+impl YieldStrategy for NeverYield {
+    async fn after_block(&mut self) {}
+}
+
+/// A strategy that yields after every block.
+///
+/// Uses `tokio::task::yield_now` inside a tokio runtime, and the portable
+/// [`yield_now`](crate::yield_now) anywhere else.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AlwaysYield;
+
+impl YieldStrategy for AlwaysYield {
+    async fn after_block(&mut self) {
+        yield_to_runtime().await
+    }
+}
+
+/// A strategy that consumes a unit of tokio's cooperative budget after every block.
+///
+/// The task only yields once it ran out of budget, that is when the runtime wants the
+/// worker back.
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CoopBudget;
+
+#[cfg(feature = "tokio")]
+impl YieldStrategy for CoopBudget {
+    async fn after_block(&mut self) {
+        tokio::task::consume_budget().await
+    }
+}
+
+/// A strategy that yields once every `n` blocks.
+#[derive(Clone, Copy, Debug)]
+pub struct EveryNBlocks {
+    /// The number of blocks folded between two yields.
+    n: usize,
+    /// The number of blocks folded since the last yield.
+    count: usize,
+}
+
+impl EveryNBlocks {
+    /// Creates a strategy yielding once every `n` blocks.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn new(n: usize) -> Self {
+        assert!(n > 0, "must yield at most once per block");
+        EveryNBlocks { n, count: 0 }
+    }
+}
+
+impl YieldStrategy for EveryNBlocks {
+    fn start(&mut self) {
+        self.count = 0;
+    }
+
+    async fn after_block(&mut self) {
+        self.count += 1;
+        if self.count == self.n {
+            self.count = 0;
+            yield_to_runtime().await
+        }
+    }
+}
+
+/// A strategy that yields once the fold ran for `budget` since it last yielded.
+#[derive(Clone, Copy, Debug)]
+pub struct TimeBudget {
+    /// The time the fold may run without yielding.
+    budget: Duration,
+    /// The last time the fold started or yielded.
+    since: Option<Instant>,
+}
+
+impl TimeBudget {
+    /// Creates a strategy yielding once the fold ran for `budget`.
+    pub fn new(budget: Duration) -> Self {
+        TimeBudget { budget, since: None }
+    }
+}
+
+impl YieldStrategy for TimeBudget {
+    fn start(&mut self) {
+        self.since = Some(Instant::now());
+    }
+
+    async fn after_block(&mut self) {
+        let since = *self.since.get_or_insert_with(Instant::now);
+        if since.elapsed() >= self.budget {
+            yield_to_runtime().await;
+            self.since = Some(Instant::now());
+        }
+    }
+}
+
+/// A strategy running a custom async hook between blocks.
+///
+/// Created with [`yield_with`].
+#[derive(Clone, Copy, Debug)]
+pub struct YieldWith<H> {
+    /// The hook run between two blocks.
+    hook: H,
+}
+
+/// Creates a strategy running `hook` between two blocks.
+///
+/// The hook decides by itself whether to yield, for instance by awaiting
+/// [`yield_now`](crate::yield_now) only when a shared load signal is raised.
+pub fn yield_with<H, Fut>(hook: H) -> YieldWith<H>
+where
+    H: FnMut() -> Fut + Send,
+    Fut: Future<Output = ()> + Send,
+{
+    YieldWith { hook }
+}
+
+impl<H, Fut> YieldStrategy for YieldWith<H>
+where
+    H: FnMut() -> Fut + Send,
+    Fut: Future<Output = ()> + Send,
+{
+    async fn after_block(&mut self) {
+        (self.hook)().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Waker};
+
+    /// Polls `after_block` once, returning whether the strategy yielded.
+    fn yields<Y: YieldStrategy>(strategy: &mut Y) -> bool {
+        let mut cx = Context::from_waker(Waker::noop());
+        std::pin::pin!(strategy.after_block()).poll(&mut cx).is_pending()
+    }
+
+    #[test]
+    fn test_never_and_always() {
+        assert!(!yields(&mut NeverYield));
+        assert!(yields(&mut AlwaysYield));
+    }
+
+    #[test]
+    fn test_every_n_blocks() {
+        let mut strategy = EveryNBlocks::new(3);
+        strategy.start();
+        let pattern: Vec<bool> = (0..6).map(|_| yields(&mut strategy)).collect();
+        assert_eq!(pattern, vec![false, false, true, false, false, true]);
+    }
+
+    #[test]
+    fn test_time_budget() {
+        let mut strategy = TimeBudget::new(Duration::from_millis(5));
+        strategy.start();
+        assert!(!yields(&mut strategy));
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(yields(&mut strategy));
+    }
+
+    #[test]
+    fn test_yield_with_load_signal() {
+        let overloaded = Arc::new(AtomicBool::new(false));
+        let mut strategy = {
+            let overloaded = overloaded.clone();
+            yield_with(move || {
+                let overloaded = overloaded.load(Ordering::Relaxed);
+                async move {
+                    if overloaded {
+                        crate::yield_now().await
+                    }
+                }
+            })
+        };
+        assert!(!yields(&mut strategy));
+        overloaded.store(true, Ordering::Relaxed);
+        assert!(yields(&mut strategy));
+    }
+}
@@ -0,0 +1,10 @@
+//! Pipelines that still can't compile with stateful `FnMut` closures.
+
+// The expected errors are those of the default features.
+#![cfg(feature = "tokio")]
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,13 @@
+// A mapping closure can't return a borrow of its own state: the next call could change it
+// while the previous item is still being folded.
+use async_iterator::{from_iter, AsyncIterator};
+
+#[tokio::main]
+async fn main() {
+    let mut last = String::new();
+    let words = from_iter(["a", "b", "c"]).map(move |word| {
+        last.push_str(word);
+        last.as_str()
+    });
+    let _ = words.count().await;
+}
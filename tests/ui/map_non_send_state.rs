@@ -0,0 +1,15 @@
+// The folds of `AsyncIterator` return `Send` futures, so the state of a mapping closure must be `Send`.
+use std::cell::Cell;
+use std::rc::Rc;
+
+use async_iterator::{from_iter, AsyncIterator};
+
+#[tokio::main]
+async fn main() {
+    let seen = Rc::new(Cell::new(0));
+    let count = move |x: u32| {
+        seen.set(seen.get() + 1);
+        x
+    };
+    let _ = from_iter(0..10u32).map(count).count().await;
+}
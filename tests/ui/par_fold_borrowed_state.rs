@@ -0,0 +1,14 @@
+// Every task of a parallel fold gets its own copy of the folding closure, which must be
+// `Clone` and `'static`: it can't update state borrowed from the caller.
+use async_iterator::{IntoAsyncIterator, ParallelAsyncIterator};
+
+#[tokio::main]
+async fn main() {
+    let mut calls = 0;
+    let sum = |acc: u64, x: u64| {
+        calls += 1;
+        acc + x
+    };
+    let data: Vec<u64> = (0..1_000).collect();
+    let _ = data.into_async_iter().par_fold(|| 0, sum, |a, b| a + b).await;
+}